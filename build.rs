@@ -0,0 +1,45 @@
+use std::{env, fs, path::Path};
+
+fn numbered(path: &Path, prefix: &str, suffix: &str) -> Option<usize> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+fn sorted(dir: &Path, prefix: &str, suffix: &str) -> Vec<(usize, std::path::PathBuf)> {
+    let mut entries = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            Some((numbered(&path, prefix, suffix)?, path))
+        })
+        .collect::<Vec<_>>();
+
+    entries.sort();
+    entries
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut modules = String::new();
+
+    for (year, year_dir) in sorted(&root, "aoc", "") {
+        if !year_dir.join("mod.rs").exists() {
+            continue;
+        }
+
+        modules.push_str(&format!(
+            "#[path = {:?}]\nmod aoc{};\n",
+            year_dir.join("mod.rs").display().to_string(),
+            year
+        ));
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("challenges.rs");
+    fs::write(out, modules).unwrap();
+}
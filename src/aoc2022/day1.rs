@@ -6,7 +6,7 @@ use itertools::Itertools;
 pub struct Day1;
 
 impl Challenge for Day1 {
-    aoc!(year = 2022, day = 1);
+    aoc!(Day1, year = 2022, day = 1);
 
     fn solve(input: String) -> (String, String) {
         let weights = input
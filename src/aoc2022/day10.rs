@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day10;
 
 impl Challenge for Day10 {
-    aoc!(year = 2022, day = 10);
+    aoc!(Day10, year = 2022, day = 10);
 
     fn solve(input: String) -> (String, String) {
         let instructions = input.lines().map(parse_ins).collect::<Vec<_>>();
@@ -8,7 +8,7 @@ use regex::Regex;
 pub struct Day11;
 
 impl Challenge for Day11 {
-    aoc!(year = 2022, day = 11);
+    aoc!(Day11, year = 2022, day = 11);
 
     fn solve(input: String) -> (String, String) {
         let mut monkeys1 = input.split("\n\n").map(parse_monkey).collect::<Vec<_>>();
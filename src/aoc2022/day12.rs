@@ -9,7 +9,7 @@ const EXIT_ELEV: u8 = b'z';
 pub struct Day12;
 
 impl Challenge for Day12 {
-    aoc!(year = 2022, day = 12);
+    aoc!(Day12, year = 2022, day = 12);
 
     fn solve(input: String) -> (String, String) {
         let map = input
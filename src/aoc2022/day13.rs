@@ -7,7 +7,7 @@ use std::str;
 pub struct Day13;
 
 impl Challenge for Day13 {
-    aoc!(year = 2022, day = 13);
+    aoc!(Day13, year = 2022, day = 13);
 
     fn solve(input: String) -> (String, String) {
         let mut lists = input.split_whitespace().map(parse_list).collect::<Vec<_>>();
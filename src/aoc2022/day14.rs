@@ -14,7 +14,7 @@ type Map = Vec<Vec<char>>;
 pub struct Day14;
 
 impl Challenge for Day14 {
-    aoc!(year = 2022, day = 14);
+    aoc!(Day14, year = 2022, day = 14);
 
     fn solve(input: String) -> (String, String) {
         let mut lines = input.lines().map(parse_lines).collect::<Vec<Line>>();
@@ -55,8 +55,9 @@ impl Challenge for Day14 {
                 }
 
                 if prev.1 == curr.1 {
-                    for j in cmp::min(prev.0, curr.0)..=cmp::max(prev.0, curr.0) {
-                        map[curr.1][j] = ROCK;
+                    let row = &mut map[curr.1];
+                    for cell in &mut row[cmp::min(prev.0, curr.0)..=cmp::max(prev.0, curr.0)] {
+                        *cell = ROCK;
                     }
                 }
 
@@ -13,7 +13,7 @@ struct Sensor {
 pub struct Day15;
 
 impl Challenge for Day15 {
-    aoc!(year = 2022, day = 15);
+    aoc!(Day15, year = 2022, day = 15);
 
     fn solve(input: String) -> (String, String) {
         let lines = input.lines();
@@ -12,7 +12,7 @@ use regex::Regex;
 pub struct Day16;
 
 impl Challenge for Day16 {
-    aoc!(year = 2022, day = 16);
+    aoc!(Day16, year = 2022, day = 16);
 
     fn solve(input: String) -> (String, String) {
         let valves = input.lines().map(parse_valve).collect::<Vec<_>>();
@@ -306,10 +306,11 @@ impl<'a> Solver<'a> {
     fn solve2(&mut self, total: usize, idx: usize, time: u32, opened: Bitmap) {
         let mut stuck = true;
 
-        if let Some(heuristic) = self.heuristic {
-            if time < heuristic && total < self.best / 2 {
-                return;
-            }
+        if let Some(heuristic) = self.heuristic
+            && time < heuristic
+            && total < self.best / 2
+        {
+            return;
         }
 
         let value = self.graph.get_value(idx);
@@ -22,7 +22,7 @@ type Cycle = ((usize, usize), (usize, usize));
 pub struct Day17;
 
 impl Challenge for Day17 {
-    aoc!(year = 2022, day = 17);
+    aoc!(Day17, year = 2022, day = 17);
 
     fn solve(input: String) -> (String, String) {
         let moves = input
@@ -150,7 +150,7 @@ fn drop(
 
     for (i, &row) in room.iter().rev().take(PADDING).enumerate() {
         if row != EMPTY_ROW {
-            room.extend(iter::repeat(EMPTY_ROW).take(PADDING - i));
+            room.extend(iter::repeat_n(EMPTY_ROW, PADDING - i));
             break;
         }
     }
@@ -173,10 +173,10 @@ fn solve(rocks: &[Rock], moves: &[Move], n: usize) -> usize {
         heights.push(height);
 
         if let Some(c) = cycle {
-            if let Some(prev) = cycles.last() {
-                if prev.0 .0 != c.0 .0 - 1 {
-                    cycles.clear();
-                }
+            if let Some(prev) = cycles.last()
+                && prev.0 .0 != c.0 .0 - 1
+            {
+                cycles.clear();
             }
 
             cycles.push(c);
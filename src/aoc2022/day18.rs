@@ -8,7 +8,7 @@ use ndarray::Array3;
 pub struct Day18;
 
 impl Challenge for Day18 {
-    aoc!(year = 2022, day = 18);
+    aoc!(Day18, year = 2022, day = 18);
 
     fn solve(input: String) -> (String, String) {
         let cubes = input
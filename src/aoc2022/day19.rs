@@ -1,27 +1,31 @@
-use lib::aoc;
-use lib::challenge::Challenge;
+use lib::challenge::ThreadedChallenge;
+use lib::executor::WorkerGroup;
+use lib::{aoc, workers};
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
 pub struct Day19;
 
-impl Challenge for Day19 {
-    aoc!(year = 2022, day = 19);
+impl ThreadedChallenge for Day19 {
+    aoc!(Day19, year = 2022, day = 19);
 
-    fn solve(input: String) -> (String, String) {
+    workers!(4);
+    fn solve(input: String, workers: &mut WorkerGroup) -> (String, String) {
         let blueprints = input.lines().map(parse_blueprint).collect::<Vec<_>>();
 
-        let res1 = blueprints
-            .iter()
+        let res1 = workers
+            .par_map(blueprints.as_slice(), |bp| solve(24, 1000, bp))
+            .into_iter()
             .enumerate()
-            .map(|(i, bp)| (i + 1) * solve(24, 1000, bp))
+            .map(|(i, geodes)| (i + 1) * geodes)
             .sum::<usize>();
 
-        let res2 = blueprints
-            .iter()
-            .take(3)
-            .map(|bp| solve(32, 2000, bp))
+        let res2 = workers
+            .par_map(&blueprints[..blueprints.len().min(3)], |bp| {
+                solve(32, 2000, bp)
+            })
+            .into_iter()
             .product::<usize>();
 
         (res1.to_string(), res2.to_string())
@@ -122,6 +126,8 @@ impl State {
     }
 }
 
+// every branch restores the state after pushing, even the last one
+#[allow(unused_assignments)]
 fn solve(time: i32, max_branches: usize, bp: &Blueprint) -> usize {
     let mut states = vec![State::new()];
     for i in 0..time {
@@ -4,7 +4,7 @@ use lib::aoc;
 pub struct Day2;
 
 impl Challenge for Day2 {
-    aoc!(year = 2022, day = 2);
+    aoc!(Day2, year = 2022, day = 2);
 
     fn solve(input: String) -> (String, String) {
         let (res1, res2) = input.lines().fold((0, 0), |acc, line| {
@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day20;
 
 impl Challenge for Day20 {
-    aoc!(year = 2022, day = 20);
+    aoc!(Day20, year = 2022, day = 20);
 
     fn solve(input: String) -> (String, String) {
         let values = input
@@ -13,7 +13,7 @@ type Monkeys<'a> = HashMap<&'a str, Yell<'a>>;
 pub struct Day21;
 
 impl Challenge for Day21 {
-    aoc!(year = 2022, day = 21);
+    aoc!(Day21, year = 2022, day = 21);
 
     fn solve(input: String) -> (String, String) {
         let res1 = {
@@ -69,21 +69,20 @@ fn reduce(monkeys: &mut Monkeys) {
         .collect::<Vec<_>>();
 
     for key in keys {
-        if let Yell::Expr(a, b, op) = monkeys.get(key).unwrap() {
-            if let Yell::Number(a) = monkeys.get(a).unwrap() {
-                if let Yell::Number(b) = monkeys.get(b).unwrap() {
-                    monkeys.insert(
-                        key,
-                        Yell::Number(match op {
-                            '+' => a + b,
-                            '-' => a - b,
-                            '*' => a * b,
-                            '/' => a / b,
-                            _ => unreachable!(),
-                        }),
-                    );
-                }
-            }
+        if let Yell::Expr(a, b, op) = monkeys.get(key).unwrap()
+            && let Yell::Number(a) = monkeys.get(a).unwrap()
+            && let Yell::Number(b) = monkeys.get(b).unwrap()
+        {
+            monkeys.insert(
+                key,
+                Yell::Number(match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    '*' => a * b,
+                    '/' => a / b,
+                    _ => unreachable!(),
+                }),
+            );
         }
     }
 }
@@ -115,7 +114,7 @@ fn solve(monkeys: &Monkeys, key: &str, res: i64, me: &str) -> i64 {
         let val_a = value(monkeys, a, me);
         let val_b = value(monkeys, b, me);
 
-        return match (val_a, op, val_b) {
+        match (val_a, op, val_b) {
             (Some(x), '+', None) => solve(monkeys, b, res - x, me),
             (None, '+', Some(x)) => solve(monkeys, a, res - x, me),
 
@@ -129,7 +128,7 @@ fn solve(monkeys: &Monkeys, key: &str, res: i64, me: &str) -> i64 {
             (None, '/', Some(x)) => solve(monkeys, a, x * res, me),
 
             _ => unreachable!(),
-        };
+        }
     } else {
         unreachable!()
     }
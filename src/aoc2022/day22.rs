@@ -8,7 +8,7 @@ const BLOCK: char = '#';
 pub struct Day22;
 
 impl Challenge for Day22 {
-    aoc!(year = 2022, day = 22);
+    aoc!(Day22, year = 2022, day = 22);
 
     fn solve(input: String) -> (String, String) {
         let (map, instructions) = input.split_once("\n\n").unwrap();
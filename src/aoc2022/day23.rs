@@ -14,21 +14,20 @@ type Map = Vec<Vec<char>>;
 pub struct Day23;
 
 impl Challenge for Day23 {
-    aoc!(year = 2022, day = 23);
+    aoc!(Day23, year = 2022, day = 23);
 
     fn solve(input: String) -> (String, String) {
         let n = input.lines().next().unwrap().len();
         let pad = n;
 
-        let mut map = iter::repeat(iter::repeat('.').take(pad * 2 + n).collect::<Vec<_>>())
-            .take(pad)
+        let empty = iter::repeat_n('.', pad * 2 + n).collect::<Vec<_>>();
+        let mut map = iter::repeat_n(empty.clone(), pad)
             .chain(input.lines().map(|l| {
-                iter::repeat('.')
-                    .take(pad)
-                    .chain(l.chars().chain(iter::repeat('.').take(pad)))
+                iter::repeat_n('.', pad)
+                    .chain(l.chars().chain(iter::repeat_n('.', pad)))
                     .collect::<Vec<_>>()
             }))
-            .chain(iter::repeat(iter::repeat('.').take(pad * 2 + n).collect::<Vec<_>>()).take(pad))
+            .chain(iter::repeat_n(empty, pad))
             .collect::<Vec<_>>();
 
         let (mut res1, mut res2) = (0, 0);
@@ -117,7 +116,7 @@ fn calculate_moves(map: &Map, round: usize) -> Vec<Move> {
         for j in 0..m {
             let pos = (i, j);
             if map[i][j] == ELF && neighs(pos).into_iter().any(|(i, j)| map[i][j] == ELF) {
-                let posibilities = vec![
+                let posibilities = [
                     (north(pos), (i - 1, j)),
                     (south(pos), (i + 1, j)),
                     (west(pos), (i, j - 1)),
@@ -14,7 +14,7 @@ type Map = Vec<Vec<char>>;
 pub struct Day24;
 
 impl Challenge for Day24 {
-    aoc!(year = 2022, day = 24);
+    aoc!(Day24, year = 2022, day = 24);
 
     fn solve(input: String) -> (String, String) {
         let mut map: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
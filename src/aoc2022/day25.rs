@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day25;
 
 impl Challenge for Day25 {
-    aoc!(year = 2022, day = 25);
+    aoc!(Day25, year = 2022, day = 25);
 
     fn solve(input: String) -> (String, String) {
         let res = input.lines().map(String::from).reduce(add_snafu).unwrap();
@@ -6,7 +6,7 @@ use std::collections::HashSet;
 pub struct Day3;
 
 impl Challenge for Day3 {
-    aoc!(year = 2022, day = 3);
+    aoc!(Day3, year = 2022, day = 3);
 
     fn solve(input: String) -> (String, String) {
         let rucks = input.lines().collect::<Vec<&str>>();
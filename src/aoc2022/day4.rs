@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day4;
 
 impl Challenge for Day4 {
-    aoc!(year = 2022, day = 4);
+    aoc!(Day4, year = 2022, day = 4);
 
     fn solve(input: String) -> (String, String) {
         let (mut res1, mut res2) = (0, 0);
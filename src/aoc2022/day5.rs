@@ -7,7 +7,7 @@ use std::str::FromStr;
 pub struct Day5;
 
 impl Challenge for Day5 {
-    aoc!(year = 2022, day = 5);
+    aoc!(Day5, year = 2022, day = 5);
 
     fn solve(input: String) -> (String, String) {
         let lines = input.lines().collect::<Vec<_>>();
@@ -85,7 +85,10 @@ impl Step {
         self
     }
 
-    fn get_stacks(&self, stacks: &mut [Vec<char>]) -> (&mut Vec<char>, &mut Vec<char>) {
+    fn get_stacks<'a>(
+        &self,
+        stacks: &'a mut [Vec<char>],
+    ) -> (&'a mut Vec<char>, &'a mut Vec<char>) {
         assert!(self.to != self.from);
         unsafe {
             let to = &mut *(stacks.get_unchecked_mut(self.to - 1) as *mut _);
@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day6;
 
 impl Challenge for Day6 {
-    aoc!(year = 2022, day = 6);
+    aoc!(Day6, year = 2022, day = 6);
 
     fn solve(input: String) -> (String, String) {
         let fst = first_distinct_seq(&input, 4).unwrap();
@@ -4,7 +4,7 @@ use lib::challenge::Challenge;
 pub struct Day7;
 
 impl Challenge for Day7 {
-    aoc!(year = 2022, day = 7);
+    aoc!(Day7, year = 2022, day = 7);
 
     fn solve(input: String) -> (String, String) {
         let mut root = Entry::Dir(vec![], 0);
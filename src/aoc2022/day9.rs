@@ -7,7 +7,7 @@ use std::collections::HashSet;
 pub struct Day9;
 
 impl Challenge for Day9 {
-    aoc!(year = 2022, day = 9);
+    aoc!(Day9, year = 2022, day = 9);
 
     fn solve(input: String) -> (String, String) {
         let mut short_rope = vec![(0, 0); 2];
@@ -12,7 +12,7 @@ enum Dir {
 }
 
 impl Challenge for Day10 {
-    aoc!(year = 2023, day = 10);
+    aoc!(Day10, year = 2023, day = 10);
 
     fn solve(input: String) -> (String, String) {
         let map = Map::from_slices(&input.lines().map(|line| line.as_bytes()).collect_vec());
@@ -4,7 +4,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day11;
 
 impl Challenge for Day11 {
-    aoc!(year = 2023, day = 11);
+    aoc!(Day11, year = 2023, day = 11);
 
     fn solve(input: String) -> (String, String) {
         let galaxies = input
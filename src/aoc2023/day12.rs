@@ -5,7 +5,7 @@ use ndarray::Array3;
 pub struct Day12;
 
 impl Challenge for Day12 {
-    aoc!(year = 2023, day = 12);
+    aoc!(Day12, year = 2023, day = 12);
 
     fn solve(input: String) -> (String, String) {
         let lines = input
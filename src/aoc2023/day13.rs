@@ -8,7 +8,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day13;
 
 impl Challenge for Day13 {
-    aoc!(year = 2023, day = 13);
+    aoc!(Day13, year = 2023, day = 13);
 
     fn solve(input: String) -> (String, String) {
         let (fst, snd) = input.split("\n\n")
@@ -6,7 +6,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day14;
 
 impl Challenge for Day14 {
-    aoc!(year = 2023, day = 14);
+    aoc!(Day14, year = 2023, day = 14);
 
     fn solve(input: String) -> (String, String) {
         let mut map = input
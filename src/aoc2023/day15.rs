@@ -5,7 +5,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day15;
 
 impl Challenge for Day15 {
-    aoc!(year = 2023, day = 15);
+    aoc!(Day15, year = 2023, day = 15);
 
     fn solve(input: String) -> (String, String) {
         let sequences = input.split(',');
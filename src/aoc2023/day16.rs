@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge, helpers::Bitset};
 pub struct Day16;
 
 impl Challenge for Day16 {
-    aoc!(year = 2023, day = 16);
+    aoc!(Day16, year = 2023, day = 16);
 
     fn solve(input: String) -> (String, String) {
         let map = input.lines().map(|line| line.as_bytes()).collect_vec();
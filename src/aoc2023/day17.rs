@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day17;
 
 impl Challenge for Day17 {
-    aoc!(year = 2023, day = 17);
+    aoc!(Day17, year = 2023, day = 17);
 
     fn solve(input: String) -> (String, String) {
         let map: Box<[Vec<u8>]> = input
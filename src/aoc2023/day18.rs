@@ -11,7 +11,7 @@ use lib::{
 pub struct Day18;
 
 impl Challenge for Day18 {
-    aoc!(year = 2023, day = 18);
+    aoc!(Day18, year = 2023, day = 18);
 
     fn solve(input: String) -> (String, String) {
         let commands = input.lines().map(parse);
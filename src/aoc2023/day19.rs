@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day19;
 
 impl Challenge for Day19 {
-    aoc!(year = 2023, day = 19);
+    aoc!(Day19, year = 2023, day = 19);
 
     fn solve(input: String) -> (String, String) {
         let (rules, parts) = input.split_once("\n\n").unwrap();
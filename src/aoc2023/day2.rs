@@ -6,7 +6,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day2;
 
 impl Challenge for Day2 {
-    aoc!(year = 2023, day = 2);
+    aoc!(Day2, year = 2023, day = 2);
 
     fn solve(input: String) -> (String, String) {
         let games = input
@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day20;
 
 impl Challenge for Day20 {
-    aoc!(year = 2023, day = 20);
+    aoc!(Day20, year = 2023, day = 20);
 
     fn solve(input: String) -> (String, String) {
         let mut mods = parse(&input);
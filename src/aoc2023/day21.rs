@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge, helpers::Map};
 pub struct Day21;
 
 impl Challenge for Day21 {
-    aoc!(year = 2023, day = 21);
+    aoc!(Day21, year = 2023, day = 21);
 
     fn solve(input: String) -> (String, String) {
         let map = Map::from_slices(&input.lines().map(|line| line.as_bytes()).collect_vec());
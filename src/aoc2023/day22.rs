@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge, helpers::unchecked_parse};
 pub struct Day22;
 
 impl Challenge for Day22 {
-    aoc!(year = 2023, day = 22);
+    aoc!(Day22, year = 2023, day = 22);
 
     fn solve(input: String) -> (String, String) {
         let mut bricks = input.lines().map(parse).collect_vec();
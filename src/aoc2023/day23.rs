@@ -10,7 +10,7 @@ pub struct Day23;
 type Graph = Vec<((usize, usize), Vec<(u32, usize)>)>;
 
 impl Challenge for Day23 {
-    aoc!(year = 2023, day = 23);
+    aoc!(Day23, year = 2023, day = 23);
 
     fn solve(input: String) -> (String, String) {
         let mut map = input
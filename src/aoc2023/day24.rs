@@ -7,7 +7,7 @@ use lib::{aoc, challenge::Challenge, helpers::unchecked_parse};
 pub struct Day24;
 
 impl Challenge for Day24 {
-    aoc!(year = 2023, day = 24);
+    aoc!(Day24, year = 2023, day = 24);
 
     fn solve(input: String) -> (String, String) {
         let hailstones = input
@@ -7,67 +7,92 @@ use lib::{aoc, challenge::ThreadedChallenge, executor::WorkerGroup, workers};
 pub struct Day25;
 
 impl ThreadedChallenge for Day25 {
-    aoc!(year = 2023, day = 25);
+    aoc!(Day25, year = 2023, day = 25);
 
     workers!(8);
     fn solve(input: String, workers: &mut WorkerGroup) -> (String, String) {
         let mut nodes_map = StrMapper::new();
-
-        let mut nodes: HashMap<usize, usize> = HashMap::new();
         let mut edges = Vec::new();
 
         for line in input.lines() {
             let (name, rest) = line.split_once(": ").unwrap();
             let from = nodes_map.get(name);
-            nodes.insert(from, 1);
 
             for other in rest.split_whitespace() {
                 let to = nodes_map.get(other);
-                nodes.insert(to, 1);
                 edges.push((from, to));
             }
         }
 
-        let fst = loop {
-            let mut handles = Vec::new();
-            for worker in workers.iter_mut() {
-                let mut nodes = nodes.clone();
-                let mut edges = edges.clone();
+        let nodes = nodes_map.k;
+        let fst = workers
+            .race(|cancel| {
+                while !cancel.is_cancelled() {
+                    if let Some(res) = contract(nodes, &edges) {
+                        return Some(res);
+                    }
+                }
 
-                handles.push(worker.run(move || {
-                    let mut rng = rand::thread_rng();
+                None
+            })
+            .unwrap();
 
-                    while nodes.len() > 2 {
-                        let i = rng.gen_range(0..edges.len());
-                        let (n1, n2) = edges[i];
+        (fst.to_string(), "".to_string())
+    }
+}
 
-                        edges.swap_remove(i);
-                        *nodes.get_mut(&n1).unwrap() += nodes.remove(&n2).unwrap();
+// one run of Karger's algorithm, merging components instead of rewriting the edge list
+fn contract(nodes: usize, edges: &[(usize, usize)]) -> Option<usize> {
+    let mut rng = rand::thread_rng();
 
-                        for edge in edges.iter_mut() {
-                            if edge.0 == n2 {
-                                edge.0 = n1;
-                            }
+    let mut parent = (0..=nodes).collect::<Vec<_>>();
+    let mut size = vec![1; nodes + 1];
+    let mut components = nodes;
 
-                            if edge.1 == n2 {
-                                edge.1 = n1;
-                            }
-                        }
+    while components > 2 {
+        let (n1, n2) = edges[rng.gen_range(0..edges.len())];
+        let (mut n1, mut n2) = (find(&mut parent, n1), find(&mut parent, n2));
 
-                        edges.retain(|edge| edge.0 != edge.1);
-                    }
+        if n1 == n2 {
+            continue;
+        }
 
-                    (edges.len() == 3).then_some(nodes.values().product::<usize>())
-                }));
-            }
+        if size[n1] < size[n2] {
+            (n1, n2) = (n2, n1);
+        }
 
-            if let Some(res) = handles.into_iter().find_map(|h| h.join().unwrap()) {
-                break res;
-            }
-        };
+        parent[n2] = n1;
+        size[n1] += size[n2];
+        components -= 1;
+    }
 
-        (fst.to_string(), "".to_string())
+    let cut = edges
+        .iter()
+        .filter(|&&(n1, n2)| find(&mut parent, n1) != find(&mut parent, n2))
+        .count();
+
+    (cut == 3).then(|| {
+        (1..=nodes)
+            .filter(|&node| parent[node] == node)
+            .map(|node| size[node])
+            .product()
+    })
+}
+
+fn find(parent: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while parent[root] != root {
+        root = parent[root];
+    }
+
+    let mut node = node;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
     }
+
+    root
 }
 
 struct StrMapper {
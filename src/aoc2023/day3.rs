@@ -3,7 +3,7 @@ use lib::{aoc, challenge::Challenge, helpers::Map};
 pub struct Day3;
 
 impl Challenge for Day3 {
-    aoc!(year = 2023, day = 3);
+    aoc!(Day3, year = 2023, day = 3);
 
     fn solve(input: String) -> (String, String) {
         let map = Map::from_text(&input);
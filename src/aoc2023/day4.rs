@@ -9,7 +9,7 @@ use lib::{
 pub struct Day4;
 
 impl Challenge for Day4 {
-    aoc!(year = 2023, day = 4);
+    aoc!(Day4, year = 2023, day = 4);
 
     fn solve(input: String) -> (String, String) {
         let (mut fst, mut snd) = (0, 0);
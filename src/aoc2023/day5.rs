@@ -8,7 +8,7 @@ use lib::{
 pub struct Day5;
 
 impl Challenge for Day5 {
-    aoc!(year = 2023, day = 5);
+    aoc!(Day5, year = 2023, day = 5);
 
     fn solve(input: String) -> (String, String) {
         let mut input = input.split("\n\n");
@@ -6,7 +6,7 @@ use lib::{aoc, challenge::Challenge, helpers::unchecked_parse};
 pub struct Day6;
 
 impl Challenge for Day6 {
-    aoc!(year = 2023, day = 6);
+    aoc!(Day6, year = 2023, day = 6);
 
     fn solve(input: String) -> (String, String) {
         let (times, dists) = input
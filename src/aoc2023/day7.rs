@@ -8,7 +8,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day7;
 
 impl Challenge for Day7 {
-    aoc!(year = 2023, day = 7);
+    aoc!(Day7, year = 2023, day = 7);
 
     fn solve(input: String) -> (String, String) {
         let card_value: HashMap<char, u8> = [
@@ -8,7 +8,7 @@ use lib::{aoc, challenge::Challenge, helpers::Trie};
 pub struct Day8;
 
 impl Challenge for Day8 {
-    aoc!(year = 2023, day = 8);
+    aoc!(Day8, year = 2023, day = 8);
 
     fn solve(input: String) -> (String, String) {
         let (steps, map) = input.split_once("\n\n").unwrap();
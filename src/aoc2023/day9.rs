@@ -4,7 +4,7 @@ use lib::{aoc, challenge::Challenge, helpers::unchecked_parse};
 pub struct Day9;
 
 impl Challenge for Day9 {
-    aoc!(year = 2023, day = 9);
+    aoc!(Day9, year = 2023, day = 9);
 
     fn solve(input: String) -> (String, String) {
         let metrics = input
@@ -3,7 +3,7 @@ use lib::{aoc, challenge::Challenge};
 pub struct Day1;
 
 impl Challenge for Day1 {
-    aoc!(year = 2024, day = 1);
+    aoc!(Day1, year = 2024, day = 1);
 
     fn solve(input: String) -> (String, String) {
         let (mut xs, mut ys): (Vec<_>, Vec<_>) = input
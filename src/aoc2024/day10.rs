@@ -31,7 +31,7 @@ fn reach(pos: (i32, i32), map: &helpers::Map<u8>) -> (u32, u32) {
 pub struct Day10;
 
 impl Challenge for Day10 {
-    aoc!(year = 2024, day = 10);
+    aoc!(Day10, year = 2024, day = 10);
 
     fn solve(input: String) -> (String, String) {
         let map = helpers::Map::from_text(&input);
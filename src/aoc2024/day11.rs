@@ -35,7 +35,7 @@ fn blink(stone: u64, count: u64, cache: &mut HashMap<(u64, u64), u64>) -> u64 {
 pub struct Day11;
 
 impl Challenge for Day11 {
-    aoc!(year = 2024, day = 11);
+    aoc!(Day11, year = 2024, day = 11);
 
     fn solve(input: String) -> (String, String) {
         let stones = input
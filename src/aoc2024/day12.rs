@@ -5,7 +5,7 @@ use hashbrown::HashSet;
 pub struct Day12;
 
 impl Challenge for Day12 {
-    aoc!(year = 2024, day = 12);
+    aoc!(Day12, year = 2024, day = 12);
 
     fn solve(input: String) -> (String, String) {
         let map = helpers::Map::from_text(&input);
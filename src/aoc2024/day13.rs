@@ -3,7 +3,7 @@ use lib::prelude::*;
 pub struct Day13;
 
 impl Challenge for Day13 {
-    aoc!(year = 2024, day = 13);
+    aoc!(Day13, year = 2024, day = 13);
 
     fn solve(input: String) -> (String, String) {
         let re = regex::Regex::new(
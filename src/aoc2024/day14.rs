@@ -4,7 +4,7 @@ use lib::{helpers::unchecked_parse, prelude::*};
 pub struct Day14;
 
 impl Challenge for Day14 {
-    aoc!(year = 2024, day = 14);
+    aoc!(Day14, year = 2024, day = 14);
 
     fn solve(input: String) -> (String, String) {
         let robots = input
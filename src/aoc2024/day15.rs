@@ -89,7 +89,7 @@ fn _can_push_complex(pos: Pos, dir: Pos, map: &helpers::Map<u8>) -> bool {
 pub struct Day15;
 
 impl Challenge for Day15 {
-    aoc!(year = 2024, day = 15);
+    aoc!(Day15, year = 2024, day = 15);
 
     fn solve(input: String) -> (String, String) {
         let (map, instructions) = input.split_once("\n\n").unwrap();
@@ -58,7 +58,7 @@ fn solve(start: Pos, end: Pos, map: &mut helpers::Map<u8>) -> usize {
 pub struct Day16;
 
 impl Challenge for Day16 {
-    aoc!(year = 2024, day = 16);
+    aoc!(Day16, year = 2024, day = 16);
 
     fn solve(input: String) -> (String, String) {
         let mut map = helpers::Map::from_text(&input);
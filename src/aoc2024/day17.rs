@@ -117,7 +117,7 @@ impl Computer {
 pub struct Day17;
 
 impl Challenge for Day17 {
-    aoc!(year = 2024, day = 17);
+    aoc!(Day17, year = 2024, day = 17);
 
     fn solve(input: String) -> (String, String) {
         let (registers, program) = input.split_once("\n\n").unwrap();
@@ -8,7 +8,7 @@ use std::collections::VecDeque;
 pub struct Day18;
 
 impl Challenge for Day18 {
-    aoc!(year = 2024, day = 18);
+    aoc!(Day18, year = 2024, day = 18);
 
     fn solve(input: String) -> (String, String) {
         let n = 71;
@@ -5,7 +5,7 @@ use itertools::Itertools;
 pub struct Day19;
 
 impl Challenge for Day19 {
-    aoc!(year = 2024, day = 19);
+    aoc!(Day19, year = 2024, day = 19);
 
     fn solve(input: String) -> (String, String) {
         let (towels, designs) = input.split_once("\n\n").unwrap();
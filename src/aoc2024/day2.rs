@@ -27,7 +27,7 @@ fn is_safe(report: impl Iterator<Item = i32>) -> bool {
 }
 
 impl Challenge for Day2 {
-    aoc!(year = 2024, day = 2);
+    aoc!(Day2, year = 2024, day = 2);
 
     fn solve(input: String) -> (String, String) {
         let reports = input
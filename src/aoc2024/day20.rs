@@ -7,7 +7,7 @@ use itertools::Itertools;
 pub struct Day20;
 
 impl Challenge for Day20 {
-    aoc!(year = 2024, day = 20);
+    aoc!(Day20, year = 2024, day = 20);
 
     fn solve(input: String) -> (String, String) {
         let map = helpers::Map::from_text(&input);
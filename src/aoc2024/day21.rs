@@ -2,7 +2,7 @@ use lib::prelude::*;
 
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
-use std::iter::repeat;
+use std::iter::repeat_n;
 
 struct Keypad(HashMap<u8, (i32, i32)>, HashSet<(i32, i32)>);
 
@@ -54,17 +54,15 @@ fn solve(code: &[u8], start: u8, keypad: &Keypad) -> Vec<Vec<u8>> {
         } else {
             if keypad.contains(&(start.0, target.1)) {
                 next.push(Vec::from_iter(
-                    repeat(dj)
-                        .take(diff.1.unsigned_abs() as usize)
-                        .chain(repeat(di).take(diff.0.unsigned_abs() as usize)),
+                    repeat_n(dj, diff.1.unsigned_abs() as usize)
+                        .chain(repeat_n(di, diff.0.unsigned_abs() as usize)),
                 ));
             }
 
             if keypad.contains(&(target.0, start.1)) {
                 next.push(Vec::from_iter(
-                    repeat(di)
-                        .take(diff.0.unsigned_abs() as usize)
-                        .chain(repeat(dj).take(diff.1.unsigned_abs() as usize)),
+                    repeat_n(di, diff.0.unsigned_abs() as usize)
+                        .chain(repeat_n(dj, diff.1.unsigned_abs() as usize)),
                 ));
             }
         }
@@ -90,7 +88,7 @@ fn solve(code: &[u8], start: u8, keypad: &Keypad) -> Vec<Vec<u8>> {
 pub struct Day21;
 
 impl Challenge for Day21 {
-    aoc!(year = 2024, day = 21);
+    aoc!(Day21, year = 2024, day = 21);
 
     fn solve(input: String) -> (String, String) {
         let numeric = Keypad::from_iter(
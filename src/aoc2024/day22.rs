@@ -5,7 +5,7 @@ use lib::{helpers::unchecked_parse, prelude::*};
 pub struct Day22;
 
 impl Challenge for Day22 {
-    aoc!(year = 2024, day = 22);
+    aoc!(Day22, year = 2024, day = 22);
 
     fn solve(input: String) -> (String, String) {
         let numbers = input.lines().map(unchecked_parse::<i64>).collect_vec();
@@ -114,7 +114,7 @@ impl FromIterator<(usize, usize)> for Graph {
 pub struct Day23;
 
 impl Challenge for Day23 {
-    aoc!(year = 2024, day = 23);
+    aoc!(Day23, year = 2024, day = 23);
 
     fn solve(input: String) -> (String, String) {
         let mut mapper = Mapper::new();
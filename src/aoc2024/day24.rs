@@ -131,7 +131,7 @@ impl<'a> Circuit<'a> {
 pub struct Day24;
 
 impl Challenge for Day24 {
-    aoc!(year = 2024, day = 24);
+    aoc!(Day24, year = 2024, day = 24);
 
     fn solve(input: String) -> (String, String) {
         let (initial, gates) = input.split_once("\n\n").unwrap();
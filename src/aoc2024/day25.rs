@@ -4,7 +4,7 @@ use smallvec::SmallVec;
 pub struct Day25;
 
 impl Challenge for Day25 {
-    aoc!(year = 2024, day = 25);
+    aoc!(Day25, year = 2024, day = 25);
 
     fn solve(input: String) -> (String, String) {
         let (mut keys, mut locks) = (Vec::new(), Vec::new());
@@ -4,7 +4,7 @@ use regex::Regex;
 pub struct Day3;
 
 impl Challenge for Day3 {
-    aoc!(year = 2024, day = 3);
+    aoc!(Day3, year = 2024, day = 3);
 
     fn solve(input: String) -> (String, String) {
         let re1 = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
@@ -5,7 +5,7 @@ use smallvec::{smallvec, SmallVec};
 pub struct Day4;
 
 impl Challenge for Day4 {
-    aoc!(year = 2024, day = 4);
+    aoc!(Day4, year = 2024, day = 4);
 
     fn solve(input: String) -> (String, String) {
         let map = input
@@ -7,7 +7,7 @@ use std::{cmp::Ordering, convert::identity};
 pub struct Day5;
 
 impl Challenge for Day5 {
-    aoc!(year = 2024, day = 5);
+    aoc!(Day5, year = 2024, day = 5);
 
     fn solve(input: String) -> (String, String) {
         let (rules, updates) = input.split_once("\n\n").unwrap();
@@ -39,10 +39,10 @@ impl Challenge for Day5 {
             update
                 .iter()
                 .scan(HashSet::new(), |set, page| {
-                    if let Some(deps) = before.get(page) {
-                        if deps.intersection(set).count() > 0 {
-                            return Some(false);
-                        }
+                    if let Some(deps) = before.get(page)
+                        && deps.intersection(set).count() > 0
+                    {
+                        return Some(false);
                     }
 
                     set.insert(*page);
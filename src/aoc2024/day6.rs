@@ -64,7 +64,7 @@ fn advance(pos: Pos, dir: Dir, map: &helpers::Map<u8>) -> (Pos, Dir) {
 }
 
 impl Challenge for Day6 {
-    aoc!(year = 2024, day = 6);
+    aoc!(Day6, year = 2024, day = 6);
 
     fn solve(input: String) -> (String, String) {
         let mut map = helpers::Map::from_text(&input);
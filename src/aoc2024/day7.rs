@@ -21,7 +21,7 @@ where
 }
 
 impl Challenge for Day7 {
-    aoc!(year = 2024, day = 7);
+    aoc!(Day7, year = 2024, day = 7);
 
     fn solve(input: String) -> (String, String) {
         let eqs = input
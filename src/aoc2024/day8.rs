@@ -6,7 +6,7 @@ use itertools::Itertools;
 pub struct Day8;
 
 impl Challenge for Day8 {
-    aoc!(year = 2024, day = 8);
+    aoc!(Day8, year = 2024, day = 8);
 
     fn solve(input: String) -> (String, String) {
         let map = helpers::Map::from_text(&input);
@@ -32,7 +32,7 @@ impl Block {
 }
 
 impl Challenge for Day9 {
-    aoc!(year = 2024, day = 9);
+    aoc!(Day9, year = 2024, day = 9);
 
     fn solve(input: String) -> (String, String) {
         let mut blocks = input
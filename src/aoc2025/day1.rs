@@ -1,10 +1,16 @@
-use lib::aoc;
+use lib::{aoc, example};
 use lib::challenge::Challenge;
 
 pub struct Day1;
 
 impl Challenge for Day1 {
-    aoc!(year = 2025, day = 1);
+    aoc!(Day1, year = 2025, day = 1);
+
+    example!(
+        "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82",
+        "3",
+        "6"
+    );
 
     fn solve(input: String) -> (String, String) {
         let rules = input.lines().map(|line| {
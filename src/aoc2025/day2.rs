@@ -3,7 +3,7 @@ use lib::prelude::*;
 pub struct Day2;
 
 impl Challenge for Day2 {
-    aoc!(year = 2025, day = 2);
+    aoc!(Day2, year = 2025, day = 2);
 
     fn solve(input: String) -> (String, String) {
         let (mut fst, mut snd) = (0, 0);
@@ -37,7 +37,7 @@ impl Challenge for Day2 {
                                 break;
                             }
 
-                            copy = copy / pow;
+                            copy /= pow;
                         }
 
                         if good {
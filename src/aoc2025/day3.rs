@@ -2,41 +2,51 @@ use lib::prelude::*;
 
 pub struct Day3;
 
-impl Challenge for Day3 {
-    aoc!(year = 2025, day = 3);
-
-    fn solve(input: String) -> (String, String) {
-        let banks = input.lines().map(|line| line.as_bytes());
-
-        let max = |bytes: &[u8]| {
-            bytes
-                .iter()
-                .copied()
-                .enumerate()
-                .fold(
-                    (0, 0),
-                    |best, curr| if curr.1 > best.1 { curr } else { best },
-                )
-                .0
-        };
-
-        let solve = |bank: &[u8], count| {
-            let mut acc = 0;
-            let mut start = 0;
-            for digit in (0..count).rev() {
-                let max_pos = start + max(&bank[start..bank.len() - digit]);
-                acc = acc * 10 + (bank[max_pos] - b'0') as u64;
-                start = max_pos + 1;
-            }
-            acc
-        };
-
-        let (mut fst, mut snd) = (0, 0);
-        for bank in banks {
-            fst += solve(bank, 2);
-            snd += solve(bank, 12);
-        }
-
-        (fst.to_string(), snd.to_string())
+impl StagedChallenge for Day3 {
+    type Parsed = Vec<Vec<u8>>;
+
+    aoc!(Day3, year = 2025, day = 3);
+
+    fn parse(input: String) -> Self::Parsed {
+        input.lines().map(|line| line.as_bytes().to_vec()).collect()
+    }
+
+    fn part1(banks: &Self::Parsed) -> String {
+        banks
+            .iter()
+            .map(|bank| joltage(bank, 2))
+            .sum::<u64>()
+            .to_string()
+    }
+
+    fn part2(banks: Self::Parsed) -> String {
+        banks
+            .iter()
+            .map(|bank| joltage(bank, 12))
+            .sum::<u64>()
+            .to_string()
+    }
+}
+
+fn joltage(bank: &[u8], count: usize) -> u64 {
+    let max = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .copied()
+            .enumerate()
+            .fold(
+                (0, 0),
+                |best, curr| if curr.1 > best.1 { curr } else { best },
+            )
+            .0
+    };
+
+    let mut acc = 0;
+    let mut start = 0;
+    for digit in (0..count).rev() {
+        let max_pos = start + max(&bank[start..bank.len() - digit]);
+        acc = acc * 10 + (bank[max_pos] - b'0') as u64;
+        start = max_pos + 1;
     }
+    acc
 }
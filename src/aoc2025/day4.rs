@@ -4,7 +4,7 @@ use lib::{helpers, prelude::*};
 pub struct Day4;
 
 impl Challenge for Day4 {
-    aoc!(year = 2025, day = 4);
+    aoc!(Day4, year = 2025, day = 4);
 
     fn solve(input: String) -> (String, String) {
         let mut map = helpers::Map::from_text(&input);
@@ -24,7 +24,7 @@ impl Challenge for Day4 {
                 })
                 .collect_vec();
 
-            if remove.len() == 0 {
+            if remove.is_empty() {
                 break;
             }
 
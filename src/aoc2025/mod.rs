@@ -1,33 +1 @@
-use lib::challenge::{ChallengeObject, ThreadedChallenge};
-
 automod::dir!(pub "src/aoc2025");
-
-pub fn challenges() -> Vec<ChallengeObject> {
-    vec![
-        day1::Day1::into_obj(),
-        day2::Day2::into_obj(),
-        day3::Day3::into_obj(),
-        day4::Day4::into_obj(),
-        // day5::Day5::into_obj(),
-        // day6::Day6::into_obj(),
-        // day7::Day7::into_obj(),
-        // day8::Day8::into_obj(),
-        // day9::Day9::into_obj(),
-        // day10::Day10::into_obj(),
-        // day11::Day11::into_obj(),
-        // day12::Day12::into_obj(),
-        // day13::Day13::into_obj(),
-        // day14::Day14::into_obj(),
-        // day15::Day15::into_obj(),
-        // day16::Day16::into_obj(),
-        // day17::Day17::into_obj(),
-        // day18::Day18::into_obj(),
-        // day19::Day19::into_obj(),
-        // day20::Day20::into_obj(),
-        // day21::Day21::into_obj(),
-        // day22::Day22::into_obj(),
-        // day23::Day23::into_obj(),
-        // day24::Day24::into_obj(),
-        // day25::Day25::into_obj(),
-    ]
-}
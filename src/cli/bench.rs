@@ -0,0 +1,144 @@
+use std::{io, time};
+
+use console::style;
+use lib::{
+    bench::{BenchOptions, BenchResult},
+    challenge::{ChallengeResult, Status},
+};
+
+use super::{Args, BenchArgs};
+
+pub fn bench(args: &Args, bench: &BenchArgs) -> io::Result<()> {
+    let options = BenchOptions {
+        warmup: bench.warmup,
+        runs: bench.runs,
+        budget: bench.budget.map(time::Duration::from_secs_f64),
+    };
+
+    let mut challenges = args.select(&bench.filter)?;
+
+    if challenges.is_empty() {
+        println!("No challenges to benchmark.");
+        return Ok(());
+    }
+
+    args.load_inputs(&mut challenges, bench.input.as_deref(), false)?;
+
+    if let Some(timeout) = args
+        .settings
+        .get("timeout")?
+        .map(time::Duration::from_secs_f64)
+    {
+        challenges
+            .iter_mut()
+            .for_each(|c| c.timeout = c.timeout.or(Some(timeout)));
+    }
+
+    let header = format!(
+        "Benchmarking {} challenges ({} runs, {} warmup)",
+        challenges.len(),
+        options.runs,
+        options.warmup
+    );
+    println!("\n {}", style(header).bold().green());
+
+    let mut executor = args.executor()?;
+    let count = challenges.len();
+
+    let start = time::Instant::now();
+    let results = challenges
+        .into_iter()
+        .map(|challenge| executor.bench(challenge, &options))
+        .collect::<Vec<_>>();
+    let time = time::Instant::now() - start;
+
+    println!();
+    show_bench(results);
+
+    let footer = format!("Benchmarked {} challenges in {:.2?}", count, time);
+    println!("\n {}", style(footer).bold().green());
+
+    Ok(())
+}
+
+fn show_bench(results: Vec<Result<BenchResult, Box<ChallengeResult>>>) {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    let table = results
+        .into_iter()
+        .map(|result| match result {
+            Ok(result) => vec![
+                result
+                    .year
+                    .cell()
+                    .foreground_color(Some(Color::Blue))
+                    .justify(Justify::Right),
+                result
+                    .day
+                    .cell()
+                    .foreground_color(Some(Color::Green))
+                    .justify(Justify::Right),
+                result.runs.cell().justify(Justify::Right),
+                format!("{:.2?}", result.min)
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+                format!("{:.2?}", result.median)
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+                format!("{:.2?}", result.mean)
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+                format!("± {:.2?}", result.stddev)
+                    .cell()
+                    .foreground_color(Some(Color::Red))
+                    .justify(Justify::Right),
+            ],
+            Err(result) => vec![
+                result
+                    .year
+                    .cell()
+                    .foreground_color(Some(Color::Blue))
+                    .justify(Justify::Right),
+                result
+                    .day
+                    .cell()
+                    .foreground_color(Some(Color::Green))
+                    .justify(Justify::Right),
+                result
+                    .status
+                    .to_string()
+                    .cell()
+                    .foreground_color(Some(Color::Red))
+                    .bold(true)
+                    .justify(Justify::Right),
+                match result.status {
+                    Status::Failed(ref message) => message.as_str(),
+                    _ => "",
+                }
+                .cell()
+                .foreground_color(Some(Color::Red)),
+                "".cell(),
+                "".cell(),
+                "".cell(),
+            ],
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Year", "Day", "Runs", "Min", "Median", "Mean", "Stddev"]
+        .iter()
+        .map(|name| {
+            style(name)
+                .red()
+                .bold()
+                .to_string()
+                .cell()
+                .bold(true)
+                .justify(Justify::Center)
+        })
+        .collect::<Vec<_>>();
+
+    print_stdout(table.table().title(header)).unwrap();
+}
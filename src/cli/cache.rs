@@ -0,0 +1,39 @@
+use std::io;
+
+use console::style;
+use lib::util::challenge_key;
+
+use super::{Args, CacheCommand};
+
+pub fn cache(args: &Args, command: &CacheCommand) -> io::Result<()> {
+    let cache = args.cache()?;
+
+    match command {
+        CacheCommand::Path => println!("{}", cache.root().display()),
+
+        CacheCommand::List { filter } => {
+            for challenge in args.select(filter)? {
+                let key = challenge_key(challenge.year, challenge.day);
+                if cache.contains(&key) {
+                    println!("{:<14} {:>8} bytes", key, cache.size(&key)?);
+                }
+            }
+        }
+
+        CacheCommand::Clear { filter } => {
+            let mut removed = 0;
+            for challenge in args.select(filter)? {
+                let key = challenge_key(challenge.year, challenge.day);
+                if cache.contains(&key) {
+                    cache.remove(&key)?;
+                    removed += 1;
+                }
+            }
+
+            let footer = format!("Removed {} cached inputs", removed);
+            println!(" {}", style(footer).bold().green());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::{collections::BTreeMap, io};
+
+use console::style;
+use lib::{
+    answers::AnswerStore,
+    calendar::{self, Star},
+    history::History,
+};
+
+use super::{Args, Filter};
+
+const COLUMNS: usize = 5;
+
+pub fn calendar(args: &Args, year: Option<usize>) -> io::Result<()> {
+    let cache = args.cache()?;
+    let answers = AnswerStore::load(&cache)?;
+    let history = History::load(&cache)?;
+
+    let filter = Filter {
+        year,
+        ..Default::default()
+    };
+
+    let mut years = BTreeMap::<usize, Vec<usize>>::new();
+    if let Some(year) = year {
+        years.insert(year, Vec::new());
+    }
+
+    for challenge in args.select(&filter)? {
+        years.entry(challenge.year).or_default().push(challenge.day);
+    }
+
+    if years.is_empty() {
+        println!("No challenges found.");
+        return Ok(());
+    }
+
+    for (year, implemented) in years {
+        let days = calendar::calendar(year, &implemented, &answers, &history);
+        let stars = days
+            .iter()
+            .flat_map(|day| day.stars)
+            .filter(|star| *star == Star::Verified)
+            .count();
+
+        let header = format!(
+            "{}  {} {}/{}  {}/{} days",
+            year,
+            style("★").yellow(),
+            stars,
+            days.len() * 2,
+            implemented.len(),
+            days.len()
+        );
+        println!("\n {}", style(header).bold().green());
+
+        for week in days.chunks(COLUMNS) {
+            let line = week
+                .iter()
+                .map(|day| {
+                    let number = format!("{:>2}", day.day);
+                    let number = match day.implemented {
+                        true => style(number).blue().bold(),
+                        false => style(number).dim(),
+                    };
+
+                    let stars = day.stars.map(|star| match (day.implemented, star) {
+                        (false, Star::Unknown) => style(" ").dim(),
+                        (_, Star::Verified) => style("★").yellow().bold(),
+                        (_, Star::Unverified) => style("☆").white(),
+                        (_, Star::Placeholder) => style("~").cyan(),
+                        (_, Star::Wrong) => style("✗").red().bold(),
+                        (_, Star::Unknown) => style("·").dim(),
+                    });
+
+                    format!("{} {}{}", number, stars[0], stars[1])
+                })
+                .collect::<Vec<_>>()
+                .join("   ");
+
+            println!("   {}", line);
+        }
+    }
+
+    println!(
+        "\n {} verified  {} unverified  {} placeholder  {} wrong  {} not run",
+        style("★").yellow().bold(),
+        style("☆").white(),
+        style("~").cyan(),
+        style("✗").red().bold(),
+        style("·").dim()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::io;
+
+use console::style;
+use lib::settings::KEYS;
+
+use super::{Args, ConfigCommand};
+
+pub fn config(args: &Args, command: &ConfigCommand) -> io::Result<()> {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    match command {
+        ConfigCommand::Show => {
+            let table = KEYS
+                .iter()
+                .map(|key| {
+                    let value = match (*key, args.settings.get_string(key)) {
+                        ("session_token", Some(token)) => mask(&token),
+                        (_, Some(value)) => value,
+                        (_, None) => "-".to_string(),
+                    };
+
+                    vec![
+                        key.cell().foreground_color(Some(Color::Blue)),
+                        value.cell().foreground_color(Some(Color::Green)),
+                        args.settings
+                            .source(key)
+                            .unwrap_or("default".to_string())
+                            .cell(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            let header = ["Setting", "Value", "Source"]
+                .iter()
+                .map(|name| {
+                    style(name)
+                        .red()
+                        .bold()
+                        .to_string()
+                        .cell()
+                        .bold(true)
+                        .justify(Justify::Center)
+                })
+                .collect::<Vec<_>>();
+
+            print_stdout(table.table().title(header)).unwrap();
+        }
+    }
+
+    Ok(())
+}
+
+fn mask(token: &str) -> String {
+    let hidden = token.chars().count().saturating_sub(4);
+    let shown = token.chars().skip(hidden).collect::<String>();
+    format!("{}{}", "*".repeat(hidden.min(8)), shown)
+}
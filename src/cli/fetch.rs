@@ -0,0 +1,31 @@
+use std::io;
+
+use console::style;
+use lib::util::challenge_key;
+
+use super::{Args, Filter};
+
+pub fn fetch(args: &Args, filter: &Filter) -> io::Result<()> {
+    let cache = args.cache()?;
+
+    let missing = args
+        .select(filter)?
+        .into_iter()
+        .filter(|c| !cache.contains(challenge_key(c.year, c.day)))
+        .collect::<Vec<_>>();
+
+    if missing.is_empty() {
+        println!("All inputs are cached.");
+        return Ok(());
+    }
+
+    let mut inputs = args.inputs(true)?;
+    for challenge in missing.iter() {
+        inputs.fetch(challenge.year, challenge.day)?;
+    }
+
+    let footer = format!("Fetched {} inputs", missing.len());
+    println!("\n {}", style(footer).bold().green());
+
+    Ok(())
+}
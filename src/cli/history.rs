@@ -0,0 +1,120 @@
+use std::{io, time::Duration};
+
+use console::style;
+use lib::history::{History, HistoryEntry, format_timestamp};
+
+use super::Args;
+
+struct Revision<'a> {
+    commit: Option<&'a str>,
+    last: &'a HistoryEntry,
+    runs: usize,
+    best: Option<Duration>,
+}
+
+pub fn history(args: &Args, year: usize, day: usize) -> io::Result<()> {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    let history = History::load(&args.cache()?)?;
+
+    let mut revisions: Vec<Revision> = Vec::new();
+    for entry in history.entries(year, day) {
+        // failed and timed out runs don't say how fast a revision is
+        let duration = entry.solved().then(|| entry.duration());
+
+        match revisions.last_mut() {
+            Some(revision) if revision.commit == entry.commit.as_deref() => {
+                revision.last = entry;
+                revision.runs += 1;
+                revision.best = revision.best.into_iter().chain(duration).min();
+            }
+            _ => revisions.push(Revision {
+                commit: entry.commit.as_deref(),
+                last: entry,
+                runs: 1,
+                best: duration,
+            }),
+        }
+    }
+
+    if revisions.is_empty() {
+        println!("No history for {} day {}.", year, day);
+        return Ok(());
+    }
+
+    let mut table = Vec::new();
+    for (i, revision) in revisions.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| &revisions[i]);
+        let answer_color = |answer: &str, previous: Option<&str>| match previous {
+            Some(previous) if previous != answer => Some(Color::Red),
+            _ => None,
+        };
+
+        let (time, time_color) = match (previous.and_then(|p| p.best), revision.best) {
+            (Some(prev), Some(best)) if !prev.is_zero() => {
+                let change = best.as_secs_f64() / prev.as_secs_f64() - 1.0;
+                let color = if change > 0.0 {
+                    Color::Red
+                } else {
+                    Color::Green
+                };
+                (format!("{:.2?} ({:+.0}%)", best, change * 100.0), color)
+            }
+            (_, Some(best)) => (format!("{:.2?}", best), Color::Yellow),
+            (_, None) => ("-".to_string(), Color::Yellow),
+        };
+
+        table.push(vec![
+            format_timestamp(revision.last.timestamp).cell(),
+            revision
+                .commit
+                .unwrap_or("-")
+                .cell()
+                .foreground_color(Some(Color::Blue)),
+            revision.runs.cell().justify(Justify::Right),
+            revision
+                .last
+                .part1
+                .as_str()
+                .cell()
+                .foreground_color(answer_color(
+                    &revision.last.part1,
+                    previous.map(|p| p.last.part1.as_str()),
+                )),
+            revision
+                .last
+                .part2
+                .as_str()
+                .cell()
+                .foreground_color(answer_color(
+                    &revision.last.part2,
+                    previous.map(|p| p.last.part2.as_str()),
+                )),
+            time.cell()
+                .foreground_color(Some(time_color))
+                .justify(Justify::Right),
+            revision.last.status.as_str().cell(),
+        ]);
+    }
+
+    let header = [
+        "Date", "Commit", "Runs", "Part 1", "Part 2", "Best", "Status",
+    ]
+    .iter()
+    .map(|name| {
+        style(name)
+            .red()
+            .bold()
+            .to_string()
+            .cell()
+            .bold(true)
+            .justify(Justify::Center)
+    })
+    .collect::<Vec<_>>();
+
+    let title = format!("History of {} day {}", year, day);
+    println!("\n {}\n", style(title).bold().green());
+    print_stdout(table.table().title(header)).unwrap();
+
+    Ok(())
+}
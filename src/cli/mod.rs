@@ -0,0 +1,427 @@
+mod bench;
+mod cache;
+mod calendar;
+mod config;
+mod fetch;
+mod history;
+mod new;
+mod run;
+mod stats;
+mod submit;
+
+use std::{cmp, io, path::Path};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use lib::{
+    baseline::Baseline,
+    challenge::{self, ChallengeObject},
+    executor::AocExecutor,
+    inputs::{AocInputs, FileCache},
+    selection::Selection,
+    settings::{self, Settings},
+};
+
+use run::{Format, Sort};
+
+#[derive(Parser, Debug)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[arg(short, long, global = true)]
+    worker_threads: Option<usize>,
+
+    #[arg(long, global = true)]
+    credentials: Option<String>,
+
+    #[arg(long, global = true)]
+    inputs_cache: Option<String>,
+
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+
+    #[arg(skip)]
+    settings: Settings,
+}
+
+#[derive(clap::Args, Debug, Default, PartialEq)]
+struct Filter {
+    #[arg(short, long)]
+    year: Option<usize>,
+
+    #[arg(short, long)]
+    day: Option<usize>,
+
+    #[arg(short, long)]
+    select: Option<Selection>,
+
+    #[arg(short, long)]
+    exclude: Option<Selection>,
+
+    #[arg(long)]
+    slowest: Option<usize>,
+}
+
+impl Filter {
+    fn single(year: usize, day: usize) -> Self {
+        Self {
+            year: Some(year),
+            day: Some(day),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Default, PartialEq)]
+struct RunArgs {
+    #[command(flatten)]
+    filter: Filter,
+
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[arg(long)]
+    timeout: Option<f64>,
+
+    #[arg(long)]
+    download: bool,
+
+    #[arg(long)]
+    verify: bool,
+
+    #[arg(long)]
+    record: bool,
+
+    #[arg(long)]
+    save_baseline: bool,
+
+    #[arg(long)]
+    compare: bool,
+
+    #[arg(long)]
+    example: bool,
+
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    #[arg(long, value_enum)]
+    sort: Option<Sort>,
+
+    #[arg(long)]
+    top: Option<usize>,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    filter: Filter,
+
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+
+    #[arg(short, long)]
+    budget: Option<f64>,
+
+    #[arg(long)]
+    timeout: Option<f64>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run challenges (the default)
+    Run(RunArgs),
+
+    /// Benchmark challenges over repeated runs
+    Bench(BenchArgs),
+
+    /// Download missing inputs
+    Fetch {
+        #[command(flatten)]
+        filter: Filter,
+    },
+
+    /// Submit an answer
+    Submit {
+        #[arg(short, long)]
+        year: Option<usize>,
+
+        #[arg(short, long)]
+        day: usize,
+
+        #[arg(short, long)]
+        part: usize,
+    },
+
+    /// Scaffold a new day module
+    New {
+        #[arg(short, long)]
+        year: Option<usize>,
+
+        #[arg(short, long)]
+        day: usize,
+
+        #[arg(short, long)]
+        threaded: bool,
+
+        #[arg(short, long)]
+        fetch: bool,
+    },
+
+    /// Show implemented challenges per year
+    Stats {
+        #[command(flatten)]
+        filter: Filter,
+    },
+
+    /// Show implemented days and stars per year
+    Calendar {
+        #[arg(short, long)]
+        year: Option<usize>,
+    },
+
+    /// Inspect or clear cached inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+
+    /// Show how a day's runtime and answers evolved across commits
+    History {
+        #[arg(short, long)]
+        year: Option<usize>,
+
+        #[arg(short, long)]
+        day: usize,
+    },
+
+    /// Inspect the layered configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Print the effective settings and where they come from
+    Show,
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommand {
+    /// Print the cache directory
+    Path,
+
+    /// List cached inputs
+    List {
+        #[command(flatten)]
+        filter: Filter,
+    },
+
+    /// Remove cached inputs
+    Clear {
+        #[command(flatten)]
+        filter: Filter,
+    },
+}
+
+pub fn main() -> io::Result<()> {
+    let mut args = Args::parse();
+    args.settings = args.load_settings()?;
+
+    if args.command.is_some() && args.run != RunArgs::default() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Challenge options must come after the subcommand",
+        ));
+    }
+
+    match args.command.as_ref() {
+        None => run::run(&args, &args.run),
+        Some(Command::Run(run)) => run::run(&args, run),
+        Some(Command::Bench(bench)) => bench::bench(&args, bench),
+        Some(Command::Fetch { filter }) => fetch::fetch(&args, filter),
+        Some(Command::Submit { year, day, part }) => {
+            submit::submit(&args, args.year(*year)?, *day, *part)
+        }
+        Some(Command::New {
+            year,
+            day,
+            threaded,
+            fetch,
+        }) => new::new_day(&args, args.year(*year)?, *day, *threaded, *fetch),
+        Some(Command::Stats { filter }) => stats::stats(&args, filter),
+        Some(Command::Calendar { year }) => calendar::calendar(&args, *year),
+        Some(Command::Cache { command }) => cache::cache(&args, command),
+        Some(Command::History { year, day }) => history::history(&args, args.year(*year)?, *day),
+        Some(Command::Config { command }) => config::config(&args, command),
+    }
+}
+
+impl Args {
+    fn load_settings(&self) -> io::Result<Settings> {
+        let run = match self.command.as_ref() {
+            None => Some(&self.run),
+            Some(Command::Run(run)) => Some(run),
+            _ => None,
+        };
+
+        let format = run
+            .and_then(|run| run.format)
+            .and_then(|format| format.to_possible_value())
+            .map(|format| format.get_name().to_string());
+
+        let timeout = match self.command.as_ref() {
+            Some(Command::Bench(bench)) => bench.timeout,
+            _ => run.and_then(|run| run.timeout),
+        };
+
+        let overrides = [
+            ("worker_threads", self.worker_threads.map(|w| w.to_string())),
+            ("inputs_cache", self.inputs_cache.clone()),
+            ("base_url", self.base_url.clone()),
+            ("format", format),
+            ("timeout", timeout.map(|t| t.to_string())),
+        ];
+
+        Settings::load(
+            &settings::config_files(),
+            None,
+            self.credentials.as_deref().map(Path::new),
+            &overrides,
+        )
+    }
+
+    fn year(&self, year: Option<usize>) -> io::Result<usize> {
+        match year {
+            Some(year) => Ok(year),
+            None => self.settings.get("year")?.ok_or(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No --year given and no default year configured",
+            )),
+        }
+    }
+
+    fn cache_root(&self) -> String {
+        self.settings
+            .get_string("inputs_cache")
+            .unwrap_or("cache".into())
+    }
+
+    fn cache(&self) -> io::Result<FileCache> {
+        FileCache::open(self.cache_root())
+    }
+
+    fn inputs(&self, download: bool) -> io::Result<AocInputs> {
+        let credentials = match (download, self.settings.credentials()) {
+            (false, _) => None,
+            (true, Some(credentials)) => Some(credentials),
+            (true, None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Downloading requires the user_agent and session_token settings",
+                ));
+            }
+        };
+
+        let inputs = AocInputs::new(self.cache_root(), credentials)?;
+
+        Ok(match self.settings.get_string("base_url") {
+            Some(base_url) => inputs.with_base_url(base_url),
+            None => inputs,
+        })
+    }
+
+    fn load_inputs(
+        &self,
+        challenges: &mut [ChallengeObject],
+        input: Option<&str>,
+        download: bool,
+    ) -> io::Result<()> {
+        let Some(path) = input else {
+            return self.inputs(download)?.get_inputs(challenges);
+        };
+
+        let [challenge] = challenges else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--input requires exactly one selected challenge",
+            ));
+        };
+
+        challenge.input = AocInputs::read_override(path)?;
+        Ok(())
+    }
+
+    fn executor(&self) -> io::Result<AocExecutor> {
+        Ok(match self.settings.get("worker_threads")? {
+            Some(workers) => AocExecutor::with_workers(workers),
+            None => AocExecutor::default(),
+        })
+    }
+
+    fn select(&self, filter: &Filter) -> io::Result<Vec<ChallengeObject>> {
+        let mut challenges = registry()?;
+
+        let year = match (filter.year, filter.select.as_ref()) {
+            (None, None) => self.settings.get("year")?,
+            (year, _) => year,
+        };
+
+        if let Some(year) = year {
+            challenges.retain(|c| c.year == year);
+        }
+
+        if let Some(day) = filter.day {
+            challenges.retain(|c| c.day == day);
+        }
+
+        if let Some(select) = filter.select.as_ref() {
+            challenges.retain(|c| select.matches(c.year, c.day));
+        }
+
+        if let Some(exclude) = filter.exclude.as_ref() {
+            challenges.retain(|c| !exclude.matches(c.year, c.day));
+        }
+
+        if let Some(slowest) = filter.slowest {
+            let baseline = Baseline::load(&self.cache()?)?;
+
+            challenges.retain(|c| baseline.get(c.year, c.day).is_some());
+            challenges.sort_by_key(|c| cmp::Reverse(baseline.get(c.year, c.day)));
+            challenges.truncate(slowest);
+        }
+
+        challenges.sort_by_key(|c| (c.year, c.day));
+        Ok(challenges)
+    }
+}
+
+fn registry() -> io::Result<Vec<ChallengeObject>> {
+    let challenges = challenge::registered();
+
+    let duplicates = challenge::duplicates(&challenges);
+    if !duplicates.is_empty() {
+        let duplicates = duplicates
+            .iter()
+            .map(|(year, day)| format!("{} day {}", year, day))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Duplicate challenge registrations: {}", duplicates),
+        ));
+    }
+
+    Ok(challenges)
+}
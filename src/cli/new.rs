@@ -0,0 +1,45 @@
+use std::{fs, io, path};
+
+use console::style;
+use lib::scaffold;
+
+use super::Args;
+
+pub fn new_day(
+    args: &Args,
+    year: usize,
+    day: usize,
+    threaded: bool,
+    fetch: bool,
+) -> io::Result<()> {
+    let src = path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let year_dir = src.join(format!("aoc{}", year));
+    let day_file = year_dir.join(format!("day{}.rs", day));
+
+    if day_file.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_file.display()),
+        ));
+    }
+
+    let module_file = year_dir.join("mod.rs");
+    if !module_file.exists() {
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&module_file, scaffold::year_template(year))?;
+    }
+
+    fs::write(&day_file, scaffold::day_template(year, day, threaded))?;
+
+    let footer = format!("Created {}", day_file.display());
+    println!("\n {}", style(footer).bold().green());
+
+    if fetch {
+        args.inputs(true)?.fetch(year, day)?;
+
+        let footer = format!("Fetched input for {} day {}", year, day);
+        println!(" {}", style(footer).bold().green());
+    }
+
+    Ok(())
+}
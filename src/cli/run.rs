@@ -0,0 +1,533 @@
+use std::{cmp::Reverse, collections::HashMap, io, time};
+
+use clap::ValueEnum;
+use console::style;
+use lib::{
+    answers::{AnswerStore, Verdict},
+    baseline::Baseline,
+    challenge::{ChallengeResult, Status},
+    history::History,
+    inputs::FileCache,
+    memory::format_bytes,
+    progress::Progress,
+    report,
+    util::into_io_error,
+};
+
+use super::{Args, RunArgs};
+
+const BASELINE_THRESHOLD: f64 = 0.1;
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sort {
+    Year,
+    Day,
+    Time,
+}
+
+pub fn run(args: &Args, run: &RunArgs) -> io::Result<()> {
+    if run.input.is_some() && (run.record || run.save_baseline) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--record and --save-baseline cannot be used with --input",
+        ));
+    }
+
+    let mut challenges = args.select(&run.filter)?;
+
+    if challenges.is_empty() {
+        println!("No challenges to run.");
+        return Ok(());
+    }
+
+    let count = challenges.len();
+
+    args.load_inputs(&mut challenges, run.input.as_deref(), run.download)?;
+
+    if run.example {
+        challenges.iter_mut().for_each(|c| c.run_example = true);
+    }
+
+    if let Some(timeout) = args
+        .settings
+        .get("timeout")?
+        .map(time::Duration::from_secs_f64)
+    {
+        challenges
+            .iter_mut()
+            .for_each(|c| c.timeout = c.timeout.or(Some(timeout)));
+    }
+
+    let cache = FileCache::open(args.cache_root())?;
+    let mut baseline = Baseline::load(&cache)?;
+    let history = History::load(&cache)?;
+
+    for challenge in challenges.iter_mut() {
+        let recorded = baseline.get(challenge.year, challenge.day).or_else(|| {
+            history
+                .entries(challenge.year, challenge.day)
+                .filter(|entry| entry.solved())
+                .last()
+                .map(|entry| entry.duration())
+        });
+
+        challenge.cost = recorded.or(challenge.cost);
+    }
+
+    let format = match args.settings.get_string("format") {
+        Some(format) => Format::from_str(&format, true).map_err(into_io_error)?,
+        None => Format::default(),
+    };
+
+    let table = format == Format::Table;
+
+    if table {
+        let header = format!("Running {} challenges", count);
+        println!("\n {}", style(header).bold().green());
+    }
+
+    let mut executor = args.executor()?;
+    let workers = executor.workers();
+
+    if table {
+        let mut progress = Progress::new();
+        executor.observe(move |event| progress.handle(event));
+    }
+
+    let start = time::Instant::now();
+    let mut results = executor.run_all(challenges);
+    let time = time::Instant::now() - start;
+
+    let sort = match (run.sort, run.top) {
+        (Some(sort), _) => sort,
+        (None, Some(_)) => Sort::Time,
+        (None, None) => Sort::Year,
+    };
+
+    match sort {
+        Sort::Year => results.sort_by_key(|r| (r.year, r.day)),
+        Sort::Day => results.sort_by_key(|r| (r.day, r.year)),
+        Sort::Time => results.sort_by_key(|r| (Reverse(r.duration), r.year, r.day)),
+    }
+
+    if run.input.is_none() {
+        History::append(&cache, &results)?;
+    }
+
+    let mut answers = AnswerStore::load(&cache)?;
+
+    let verdicts = run.verify.then(|| {
+        results
+            .iter()
+            .map(|r| answers.verify(r))
+            .collect::<Vec<_>>()
+    });
+
+    if run.record {
+        results.iter().for_each(|r| answers.record(r));
+        answers.save(&cache)?;
+    }
+
+    let changes = run.compare.then(|| {
+        results
+            .iter()
+            .map(|r| baseline.change(r))
+            .collect::<Vec<_>>()
+    });
+
+    if run.save_baseline {
+        results.iter().for_each(|r| baseline.record(r));
+        baseline.save(&cache)?;
+    }
+
+    let failed = verdicts.as_ref().map_or(0, |verdicts| {
+        verdicts
+            .iter()
+            .filter(|(fst, snd)| fst.combine(*snd) == Verdict::Fail)
+            .count()
+    });
+
+    let timed_out = results
+        .iter()
+        .filter(|r| r.status == Status::TimedOut)
+        .count();
+
+    let panicked = results
+        .iter()
+        .filter(|r| matches!(r.status, Status::Failed(_)))
+        .count();
+
+    let failed_examples = results
+        .iter()
+        .filter_map(|r| r.example.as_ref())
+        .filter(|e| e.verdict.0.combine(e.verdict.1) == Verdict::Fail)
+        .count();
+
+    let summaries = report::summarize(&results);
+    let parallelism = report::parallelism(&results, time, workers);
+
+    let (mut verdicts, mut changes) = (verdicts, changes);
+    if let Some(top) = run.top {
+        let mut slowest = (0..results.len()).collect::<Vec<_>>();
+        slowest.sort_by_key(|&i| Reverse(results[i].duration));
+
+        let mut keep = vec![false; results.len()];
+        slowest.into_iter().take(top).for_each(|i| keep[i] = true);
+
+        retain(&mut results, &keep);
+        verdicts
+            .iter_mut()
+            .for_each(|verdicts| retain(verdicts, &keep));
+        changes
+            .iter_mut()
+            .for_each(|changes| retain(changes, &keep));
+    }
+
+    match format {
+        Format::Table => {
+            println!();
+            show_results(&results, verdicts, changes.as_deref(), run.example);
+
+            if count > 1 {
+                show_summaries(&summaries);
+            }
+
+            if let Some(changes) = changes.as_ref() {
+                show_changes(&results, changes);
+            }
+        }
+        Format::Json => println!("{}", report::json(&results, verdicts.as_deref())?),
+        Format::Csv => print!("{}", report::csv(&results, verdicts.as_deref())?),
+        Format::Markdown => print!("{}", report::markdown(&results, verdicts.as_deref())),
+    }
+
+    if table {
+        let footer = format!("Executed {} challanges in {:.2?}", count, time);
+        println!("\n {}", style(footer).bold().green());
+
+        let footer = format!(
+            "Sum of durations {:.2?}, {:.2}x speedup, {:.0}% efficiency on {} workers",
+            parallelism.sum,
+            parallelism.speedup,
+            parallelism.efficiency * 100.0,
+            workers
+        );
+        println!(" {}", style(footer).green());
+
+        if run.record {
+            let footer = format!("Recorded answers for {} challenges", count);
+            println!(" {}", style(footer).bold().green());
+        }
+
+        if run.save_baseline {
+            let footer = format!("Saved baseline for {} challenges", count);
+            println!(" {}", style(footer).bold().green());
+        }
+    }
+
+    if failed > 0 {
+        let footer = format!("{} challenges failed verification", failed);
+        eprintln!(" {}", style(footer).bold().red());
+    }
+
+    if failed_examples > 0 {
+        let footer = format!("{} examples did not match", failed_examples);
+        eprintln!(" {}", style(footer).bold().red());
+    }
+
+    if timed_out > 0 {
+        let footer = format!("{} challenges timed out", timed_out);
+        eprintln!(" {}", style(footer).bold().red());
+    }
+
+    if panicked > 0 {
+        let footer = format!("{} challenges panicked", panicked);
+        eprintln!(" {}", style(footer).bold().red());
+    }
+
+    if failed + failed_examples + timed_out + panicked > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+pub fn show_results(
+    results: &[ChallengeResult],
+    verdicts: Option<Vec<(Verdict, Verdict)>>,
+    changes: Option<&[Option<f64>]>,
+    examples: bool,
+) {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    let mut longest = HashMap::new();
+    for result in results {
+        let duration = result.duration.as_nanos().max(1).ilog2();
+        let entry = longest.entry(result.year).or_insert(duration);
+        *entry = duration.max(*entry);
+    }
+
+    let staged = results.iter().any(|r| r.stages.is_some());
+    let measured = results.iter().any(|r| r.memory.is_some());
+
+    let mut table = Vec::new();
+    for (i, result) in results.iter().enumerate() {
+        let duration = result.duration.as_nanos().max(1).ilog2();
+        let rel_duration = String::from("█")
+            .repeat((duration * 10 / longest[&result.year].max(1)).max(1) as usize);
+
+        let (time, time_color) = match changes.and_then(|changes| changes[i]) {
+            Some(change) if change >= BASELINE_THRESHOLD => (
+                format!("{:.2?} ({:+.0}%)", result.duration, change * 100.0),
+                Color::Red,
+            ),
+            Some(change) if change <= -BASELINE_THRESHOLD => (
+                format!("{:.2?} ({:+.0}%)", result.duration, change * 100.0),
+                Color::Green,
+            ),
+            _ => (format!("{:.2?}", result.duration), Color::Yellow),
+        };
+
+        let mut row = vec![
+            result
+                .year
+                .cell()
+                .foreground_color(Some(Color::Blue))
+                .justify(Justify::Right),
+            result
+                .day
+                .cell()
+                .foreground_color(Some(Color::Green))
+                .justify(Justify::Right),
+            match result.status {
+                Status::Solved => result.solution.0.as_str().cell(),
+                ref status => status.cell().foreground_color(Some(Color::Red)).bold(true),
+            },
+            match result.status {
+                Status::Failed(ref message) => {
+                    message.as_str().cell().foreground_color(Some(Color::Red))
+                }
+                _ => result.solution.1.as_str().cell(),
+            },
+            time.cell()
+                .foreground_color(Some(time_color))
+                .justify(Justify::Right),
+            rel_duration
+                .cell()
+                .foreground_color(Some(Color::Red))
+                .justify(Justify::Right),
+        ];
+
+        if staged {
+            match result.stages.as_ref() {
+                Some(stages) => {
+                    row.extend([stages.parse, stages.part1, stages.part2].map(|duration| {
+                        format!("{:.2?}", duration)
+                            .cell()
+                            .foreground_color(Some(Color::Yellow))
+                            .justify(Justify::Right)
+                    }))
+                }
+                None => row.extend(["-", "-", "-"].map(|s| s.cell().justify(Justify::Center))),
+            }
+        }
+
+        if measured {
+            match result.memory.as_ref() {
+                Some(memory) => row.extend([
+                    memory.allocations.cell().justify(Justify::Right),
+                    format_bytes(memory.bytes).cell().justify(Justify::Right),
+                    format_bytes(memory.peak)
+                        .cell()
+                        .foreground_color(Some(Color::Magenta))
+                        .justify(Justify::Right),
+                ]),
+                None => row.extend(["-", "-", "-"].map(|s| s.cell().justify(Justify::Center))),
+            }
+        }
+
+        if examples {
+            match result.example.as_ref() {
+                Some(example) => {
+                    for (answer, verdict) in [
+                        (&example.solution.0, example.verdict.0),
+                        (&example.solution.1, example.verdict.1),
+                    ] {
+                        let color = match verdict {
+                            Verdict::Pass => Some(Color::Green),
+                            Verdict::Fail => Some(Color::Red),
+                            Verdict::Unknown => None,
+                        };
+
+                        row.push(answer.as_str().cell().foreground_color(color));
+                    }
+                }
+                None => {
+                    row.push("-".cell().justify(Justify::Center));
+                    row.push("-".cell().justify(Justify::Center));
+                }
+            }
+        }
+
+        if let Some(verdicts) = verdicts.as_ref() {
+            let (fst, snd) = verdicts[i];
+            let verdict = fst.combine(snd);
+            let color = match verdict {
+                Verdict::Pass => Color::Green,
+                Verdict::Fail => Color::Red,
+                Verdict::Unknown => Color::Yellow,
+            };
+
+            row.push(verdict.cell().foreground_color(Some(color)).bold(true));
+        }
+
+        table.push(row);
+    }
+
+    let mut header = vec!["Year", "Day", "Part 1", "Part 2", "Time", "Relative"];
+    if staged {
+        header.extend(["Parse", "P1", "P2"]);
+    }
+
+    if measured {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+
+    if examples {
+        header.extend(["Example 1", "Example 2"]);
+    }
+
+    if verdicts.is_some() {
+        header.push("Verify");
+    }
+
+    let header = header
+        .iter()
+        .map(|name| {
+            style(name)
+                .red()
+                .bold()
+                .to_string()
+                .cell()
+                .bold(true)
+                .justify(Justify::Center)
+        })
+        .collect::<Vec<_>>();
+
+    print_stdout(table.table().title(header)).unwrap();
+}
+
+fn show_summaries(summaries: &[report::YearSummary]) {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    let table = summaries
+        .iter()
+        .map(|summary| {
+            vec![
+                summary
+                    .year
+                    .cell()
+                    .foreground_color(Some(Color::Blue))
+                    .justify(Justify::Right),
+                summary.days.cell().justify(Justify::Right),
+                summary
+                    .failed
+                    .cell()
+                    .foreground_color((summary.failed > 0).then_some(Color::Red))
+                    .justify(Justify::Right),
+                format!("{:.2?}", summary.total)
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+                summary
+                    .median
+                    .map_or("-".to_string(), |median| format!("{:.2?}", median))
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+                summary
+                    .slowest
+                    .map_or("-".to_string(), |(day, duration)| {
+                        format!("day {} ({:.2?})", day, duration)
+                    })
+                    .cell()
+                    .foreground_color(Some(Color::Red))
+                    .justify(Justify::Right),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Year", "Days", "Failed", "Total", "Median", "Slowest"]
+        .iter()
+        .map(|name| {
+            style(name)
+                .red()
+                .bold()
+                .to_string()
+                .cell()
+                .bold(true)
+                .justify(Justify::Center)
+        })
+        .collect::<Vec<_>>();
+
+    println!();
+    print_stdout(table.table().title(header)).unwrap();
+}
+
+fn retain<T>(items: &mut Vec<T>, keep: &[bool]) {
+    let mut keep = keep.iter();
+    items.retain(|_| *keep.next().unwrap());
+}
+
+fn show_changes(results: &[ChallengeResult], changes: &[Option<f64>]) {
+    let mut changed = results
+        .iter()
+        .zip(changes)
+        .filter_map(|(result, change)| Some((result, (*change)?)))
+        .filter(|(_, change)| change.abs() >= BASELINE_THRESHOLD)
+        .collect::<Vec<_>>();
+
+    changed.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let regressions = changed.iter().filter(|(_, change)| *change > 0.0).take(5);
+    let improvements = changed
+        .iter()
+        .rev()
+        .filter(|(_, change)| *change < 0.0)
+        .take(5);
+
+    for (title, entries) in [
+        ("Biggest regressions", regressions.collect::<Vec<_>>()),
+        ("Biggest improvements", improvements.collect::<Vec<_>>()),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+
+        println!("\n {}", style(title).bold());
+        for (result, change) in entries {
+            let line = format!(
+                "{} day {}: {:.2?} -> {:.2?} ({:+.0}%)",
+                result.year,
+                result.day,
+                result.duration.div_f64(1.0 + change),
+                result.duration,
+                change * 100.0
+            );
+
+            match *change > 0.0 {
+                true => println!("   {}", style(line).red()),
+                false => println!("   {}", style(line).green()),
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::BTreeMap, io, time::Duration};
+
+use console::style;
+use lib::{answers::AnswerStore, baseline::Baseline, util::challenge_key};
+
+use super::{Args, Filter};
+
+#[derive(Default)]
+struct YearStats {
+    days: usize,
+    threaded: usize,
+    examples: usize,
+    inputs: usize,
+    answers: usize,
+    baseline: Duration,
+}
+
+pub fn stats(args: &Args, filter: &Filter) -> io::Result<()> {
+    use cli_table::{Cell, Color, Style, Table, format::Justify, print_stdout};
+
+    let cache = args.cache()?;
+    let answers = AnswerStore::load(&cache)?;
+    let baseline = Baseline::load(&cache)?;
+
+    let mut years = BTreeMap::<usize, YearStats>::new();
+    for challenge in args.select(filter)? {
+        let stats = years.entry(challenge.year).or_default();
+
+        stats.days += 1;
+        stats.threaded += challenge.worker_hint.is_some() as usize;
+        stats.examples += challenge.example.is_some() as usize;
+        stats.inputs += cache.contains(challenge_key(challenge.year, challenge.day)) as usize;
+        stats.answers += answers.get(challenge.year, challenge.day).is_some() as usize;
+        stats.baseline += baseline
+            .get(challenge.year, challenge.day)
+            .unwrap_or_default();
+    }
+
+    if years.is_empty() {
+        println!("No challenges found.");
+        return Ok(());
+    }
+
+    let table = years
+        .iter()
+        .map(|(year, stats)| {
+            vec![
+                year.cell()
+                    .foreground_color(Some(Color::Blue))
+                    .justify(Justify::Right),
+                stats
+                    .days
+                    .cell()
+                    .foreground_color(Some(Color::Green))
+                    .justify(Justify::Right),
+                stats.threaded.cell().justify(Justify::Right),
+                stats.examples.cell().justify(Justify::Right),
+                stats.inputs.cell().justify(Justify::Right),
+                stats.answers.cell().justify(Justify::Right),
+                format!("{:.2?}", stats.baseline)
+                    .cell()
+                    .foreground_color(Some(Color::Yellow))
+                    .justify(Justify::Right),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let header = [
+        "Year", "Days", "Threaded", "Examples", "Inputs", "Answers", "Baseline",
+    ]
+    .iter()
+    .map(|name| {
+        style(name)
+            .red()
+            .bold()
+            .to_string()
+            .cell()
+            .bold(true)
+            .justify(Justify::Center)
+    })
+    .collect::<Vec<_>>();
+
+    println!();
+    print_stdout(table.table().title(header)).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use std::io;
+
+use console::style;
+use lib::inputs::{SubmitOutcome, Submitted};
+
+use super::{Args, Filter};
+
+pub fn submit(args: &Args, year: usize, day: usize, part: usize) -> io::Result<()> {
+    if !(1..=2).contains(&part) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Part must be either 1 or 2",
+        ));
+    }
+
+    let Some(mut challenge) = args.select(&Filter::single(year, day))?.pop() else {
+        println!("No challenge to submit.");
+        return Ok(());
+    };
+
+    let mut inputs = args.inputs(args.settings.credentials().is_some())?;
+    challenge.input = inputs.input(&challenge)?;
+
+    let result = args.executor()?.run_one(challenge);
+    let answer = match part {
+        1 => result.solution.0,
+        _ => result.solution.1,
+    };
+
+    if answer.is_empty() {
+        println!(
+            "No answer to submit for {} day {} part {}.",
+            year, day, part
+        );
+        return Ok(());
+    }
+
+    let header = format!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    println!("\n {}", style(header).bold().green());
+
+    let (outcome, note) = match inputs.submit(year, day, part, &answer)? {
+        Submitted::Sent(outcome) => (outcome, ""),
+        Submitted::Known(outcome) => (outcome, " (already submitted, not sent)"),
+    };
+
+    let footer = format!("{}{}", outcome, note);
+    match outcome {
+        SubmitOutcome::Correct => println!(" {}", style(footer).bold().green()),
+        _ => println!(" {}", style(footer).bold().red()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, fmt, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge::ChallengeResult,
+    inputs::FileCache,
+    util::{challenge_key, into_io_error},
+};
+
+const ANSWERS_FILE: &str = "answers.yaml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Verdict::Fail, _) | (_, Verdict::Fail) => Verdict::Fail,
+            (Verdict::Pass, Verdict::Pass) => Verdict::Pass,
+            _ => Verdict::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "Pass"),
+            Verdict::Fail => write!(f, "Fail"),
+            Verdict::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answer {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<String, Answer>,
+}
+
+impl AnswerStore {
+    pub fn load(cache: &FileCache) -> io::Result<Self> {
+        let answers = match cache.get(ANSWERS_FILE) {
+            Ok(contents) => serde_yaml::from_str(&contents).map_err(into_io_error)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, cache: &FileCache) -> io::Result<()> {
+        let contents = serde_yaml::to_string(&self.answers).map_err(into_io_error)?;
+        cache.set(ANSWERS_FILE, contents)
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<&Answer> {
+        self.answers.get(&challenge_key(year, day))
+    }
+
+    pub fn verify(&self, result: &ChallengeResult) -> (Verdict, Verdict) {
+        let answer = self.get(result.year, result.day);
+
+        (
+            Verdict::check(answer.and_then(|a| a.part1.as_deref()), &result.solution.0),
+            Verdict::check(answer.and_then(|a| a.part2.as_deref()), &result.solution.1),
+        )
+    }
+
+    pub fn record(&mut self, result: &ChallengeResult) {
+        if !result.solved() {
+            return;
+        }
+
+        self.answers.insert(
+            challenge_key(result.year, result.day),
+            Answer {
+                part1: Some(result.solution.0.clone()),
+                part2: Some(result.solution.1.clone()),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn result(day: usize, fst: &str, snd: &str) -> ChallengeResult {
+        let solution = (fst.to_string(), snd.to_string());
+        ChallengeResult::new(2024, day, solution, Duration::ZERO, 1)
+    }
+
+    #[test]
+    fn verify_answers() {
+        let mut store = AnswerStore::default();
+
+        assert_eq!(
+            store.verify(&result(1, "10", "20")),
+            (Verdict::Unknown, Verdict::Unknown)
+        );
+
+        store.record(&result(1, "10", "20"));
+
+        assert_eq!(
+            store.verify(&result(1, "10", "20")),
+            (Verdict::Pass, Verdict::Pass)
+        );
+        assert_eq!(
+            store.verify(&result(1, "10", "21")),
+            (Verdict::Pass, Verdict::Fail)
+        );
+        assert_eq!(
+            store.verify(&result(2, "10", "20")),
+            (Verdict::Unknown, Verdict::Unknown)
+        );
+    }
+
+    #[test]
+    fn combine_verdicts() {
+        assert_eq!(Verdict::Pass.combine(Verdict::Pass), Verdict::Pass);
+        assert_eq!(Verdict::Pass.combine(Verdict::Unknown), Verdict::Unknown);
+        assert_eq!(Verdict::Unknown.combine(Verdict::Fail), Verdict::Fail);
+    }
+
+    #[test]
+    fn parse_answers() {
+        let store = AnswerStore {
+            answers: serde_yaml::from_str("2024_day1:\n  part1: '10'\n").unwrap(),
+        };
+
+        assert_eq!(
+            store.get(2024, 1),
+            Some(&Answer {
+                part1: Some("10".to_string()),
+                part2: None
+            })
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::BTreeMap, io, time::Duration};
+
+use crate::{
+    challenge::ChallengeResult,
+    inputs::FileCache,
+    util::{challenge_key, into_io_error},
+};
+
+const BASELINE_FILE: &str = "baseline.yaml";
+
+#[derive(Debug, Default)]
+pub struct Baseline {
+    durations: BTreeMap<String, u64>,
+}
+
+impl Baseline {
+    pub fn load(cache: &FileCache) -> io::Result<Self> {
+        let durations = match cache.get(BASELINE_FILE) {
+            Ok(contents) => serde_yaml::from_str(&contents).map_err(into_io_error)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self { durations })
+    }
+
+    pub fn save(&self, cache: &FileCache) -> io::Result<()> {
+        let contents = serde_yaml::to_string(&self.durations).map_err(into_io_error)?;
+        cache.set(BASELINE_FILE, contents)
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<Duration> {
+        self.durations
+            .get(&challenge_key(year, day))
+            .map(|nanos| Duration::from_nanos(*nanos))
+    }
+
+    pub fn record(&mut self, result: &ChallengeResult) {
+        if !result.solved() {
+            return;
+        }
+
+        self.durations.insert(
+            challenge_key(result.year, result.day),
+            result.duration.as_nanos() as u64,
+        );
+    }
+
+    pub fn change(&self, result: &ChallengeResult) -> Option<f64> {
+        if !result.solved() {
+            return None;
+        }
+
+        let old = self.get(result.year, result.day)?.as_nanos() as f64;
+        let new = result.duration.as_nanos() as f64;
+        (old > 0.0).then(|| (new - old) / old)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::challenge::Status;
+
+    fn result(day: usize, micros: u64) -> ChallengeResult {
+        let duration = Duration::from_micros(micros);
+        ChallengeResult::new(2024, day, Default::default(), duration, 1)
+    }
+
+    #[test]
+    fn relative_change() {
+        let mut baseline = Baseline::default();
+        baseline.record(&result(1, 100));
+
+        assert_eq!(baseline.get(2024, 1), Some(Duration::from_micros(100)));
+        assert_eq!(baseline.change(&result(1, 150)), Some(0.5));
+        assert_eq!(baseline.change(&result(1, 50)), Some(-0.5));
+        assert_eq!(baseline.change(&result(2, 50)), None);
+
+        let mut timed_out = result(1, 500);
+        timed_out.status = Status::TimedOut;
+        baseline.record(&timed_out);
+
+        assert_eq!(baseline.change(&timed_out), None);
+        assert_eq!(baseline.get(2024, 1), Some(Duration::from_micros(100)));
+    }
+}
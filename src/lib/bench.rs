@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+    pub budget: Option<Duration>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+            budget: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    pub year: usize,
+    pub day: usize,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+// the mean of the two middle values when there is an even number of them
+pub fn median(sorted: &[Duration]) -> Option<Duration> {
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        len if len.is_multiple_of(2) => Some((sorted[mid - 1] + sorted[mid]) / 2),
+        _ => Some(sorted[mid]),
+    }
+}
+
+impl BenchResult {
+    pub fn from_samples(year: usize, day: usize, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let runs = samples.len();
+        let nanos = samples
+            .iter()
+            .map(|s| s.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+
+        let variance = if runs > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            year,
+            day,
+            runs,
+            min: samples[0],
+            median: median(&samples).unwrap(),
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_stats() {
+        let samples = [40, 10, 30, 20].map(Duration::from_micros).to_vec();
+        let result = BenchResult::from_samples(2024, 1, samples);
+
+        assert_eq!(result.runs, 4);
+        assert_eq!(result.min, Duration::from_micros(10));
+        assert_eq!(result.median, Duration::from_micros(25));
+        assert_eq!(result.mean, Duration::from_micros(25));
+        assert_eq!(result.stddev.as_nanos(), 12909);
+    }
+
+    #[test]
+    fn single_sample() {
+        let result = BenchResult::from_samples(2024, 1, vec![Duration::from_millis(3)]);
+
+        assert_eq!(result.median, Duration::from_millis(3));
+        assert_eq!(result.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{answers::AnswerStore, history::History};
+
+pub const PLACEHOLDERS: &[&str] = &["", "manual"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Star {
+    Verified,
+    Wrong,
+    Unverified,
+    Placeholder,
+    Unknown,
+}
+
+impl Star {
+    pub fn of(recorded: Option<&str>, latest: Option<&str>) -> Self {
+        // only a run that reproduces the recorded answer earns a star
+        match (recorded, latest) {
+            (_, None) => Star::Unknown,
+            (_, Some(answer)) if PLACEHOLDERS.contains(&answer) => Star::Placeholder,
+            (Some(recorded), Some(answer)) if recorded != answer => Star::Wrong,
+            (Some(_), Some(_)) => Star::Verified,
+            (None, Some(_)) => Star::Unverified,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDay {
+    pub day: usize,
+    pub implemented: bool,
+    pub stars: [Star; 2],
+}
+
+pub fn event_length(year: usize) -> usize {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+pub fn calendar(
+    year: usize,
+    implemented: &[usize],
+    answers: &AnswerStore,
+    history: &History,
+) -> Vec<CalendarDay> {
+    let length = implemented
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(event_length(year));
+
+    (1..=length)
+        .map(|day| {
+            let recorded = answers.get(year, day);
+            let latest = history
+                .entries(year, day)
+                .filter(|entry| entry.solved())
+                .last();
+
+            let part1 = Star::of(
+                recorded.and_then(|a| a.part1.as_deref()),
+                latest.map(|e| e.part1.as_str()),
+            );
+            let part2 = Star::of(
+                recorded.and_then(|a| a.part2.as_deref()),
+                latest.map(|e| e.part2.as_str()),
+            );
+
+            CalendarDay {
+                day,
+                implemented: implemented.contains(&day),
+                stars: [part1, part2],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::*;
+    use crate::{challenge::ChallengeResult, inputs::FileCache};
+
+    #[test]
+    fn stars() {
+        assert_eq!(Star::of(None, None), Star::Unknown);
+        assert_eq!(Star::of(None, Some("42")), Star::Unverified);
+        assert_eq!(Star::of(Some("42"), None), Star::Unknown);
+        assert_eq!(Star::of(Some("manual"), None), Star::Unknown);
+        assert_eq!(Star::of(Some("42"), Some("42")), Star::Verified);
+        assert_eq!(Star::of(Some("42"), Some("43")), Star::Wrong);
+        assert_eq!(Star::of(None, Some("manual")), Star::Placeholder);
+        assert_eq!(Star::of(Some("42"), Some("")), Star::Placeholder);
+    }
+
+    #[test]
+    fn days() {
+        let solved = |day, fst: &str, snd: &str| {
+            let solution = (fst.to_string(), snd.to_string());
+            ChallengeResult::new(2024, day, solution, Duration::ZERO, 1)
+        };
+
+        let mut answers = AnswerStore::default();
+        answers.record(&solved(2, "5", "6"));
+        answers.record(&solved(3, "7", "8"));
+        answers.record(&solved(14, "12", "manual"));
+
+        let root = env::temp_dir().join(format!("aoc_calendar_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = FileCache::open(&root).unwrap();
+
+        History::append(
+            &cache,
+            &[
+                solved(2, "5", "9"),
+                solved(3, "7", "8"),
+                solved(4, "1", "2"),
+                solved(14, "12", ""),
+            ],
+        )
+        .unwrap();
+        History::append(
+            &cache,
+            &[ChallengeResult::timed_out(
+                2024,
+                3,
+                Duration::from_secs(1),
+                1,
+            )],
+        )
+        .unwrap();
+
+        let history = History::load(&cache).unwrap();
+        let days = calendar(2024, &[1, 2, 3, 4, 14], &answers, &history);
+
+        assert_eq!(days.len(), 25);
+        assert!(days[0].implemented);
+        assert_eq!(days[0].stars, [Star::Unknown, Star::Unknown]);
+        assert_eq!(days[1].stars, [Star::Verified, Star::Wrong]);
+        assert_eq!(days[2].stars, [Star::Verified, Star::Verified]);
+        assert_eq!(days[3].stars, [Star::Unverified, Star::Unverified]);
+        assert!(!days[4].implemented);
+        assert_eq!(days[13].stars, [Star::Verified, Star::Placeholder]);
+
+        let days = calendar(2024, &[14], &answers, &History::default());
+        assert_eq!(days[13].stars, [Star::Unknown, Star::Unknown]);
+        assert_eq!(calendar(2025, &[], &answers, &History::default()).len(), 12);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
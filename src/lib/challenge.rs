@@ -1,16 +1,66 @@
-use std::time::{self, Duration};
+use std::{
+    any::Any,
+    cell::Cell,
+    collections::HashSet,
+    fmt, mem,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+    thread,
+    time::{self, Duration},
+};
 
-use crate::executor::WorkerGroup;
+use crate::{
+    answers::Verdict,
+    executor::WorkerGroup,
+    memory::{AllocStats, Tracker},
+};
 
-type Solver =
-    Box<dyn Fn(String, &mut WorkerGroup) -> ((String, String), Duration) + Send + 'static>;
+type Solver = Box<
+    dyn Fn(String, &mut WorkerGroup) -> ((String, String), Duration, Option<Stages>)
+        + Send
+        + 'static,
+>;
 
 pub trait Challenge {
     fn year() -> usize;
     fn day() -> usize;
     fn solve(input: String) -> (String, String);
 
-    fn example() -> Option<&'static str> {
+    fn example() -> Option<Example> {
+        None
+    }
+
+    fn timeout() -> Option<Duration> {
+        None
+    }
+
+    fn cost() -> Option<Duration> {
+        None
+    }
+
+    fn solve_staged(input: String) -> ((String, String), Option<Stages>) {
+        (Self::solve(input), None)
+    }
+}
+
+pub trait StagedChallenge {
+    type Parsed;
+
+    fn year() -> usize;
+    fn day() -> usize;
+    fn parse(input: String) -> Self::Parsed;
+    fn part1(parsed: &Self::Parsed) -> String;
+    fn part2(parsed: Self::Parsed) -> String;
+
+    fn example() -> Option<Example> {
+        None
+    }
+
+    fn timeout() -> Option<Duration> {
+        None
+    }
+
+    fn cost() -> Option<Duration> {
         None
     }
 }
@@ -21,10 +71,25 @@ pub trait ThreadedChallenge {
     fn worker_hint() -> Option<usize>;
     fn solve(input: String, workers: &mut WorkerGroup) -> (String, String);
 
-    fn example() -> Option<&'static str> {
+    fn example() -> Option<Example> {
+        None
+    }
+
+    fn timeout() -> Option<Duration> {
+        None
+    }
+
+    fn cost() -> Option<Duration> {
         None
     }
 
+    fn solve_staged(
+        input: String,
+        workers: &mut WorkerGroup,
+    ) -> ((String, String), Option<Stages>) {
+        (Self::solve(input, workers), None)
+    }
+
     fn into_obj() -> ChallengeObject {
         ChallengeObject {
             year: Self::year(),
@@ -32,12 +97,15 @@ pub trait ThreadedChallenge {
             worker_hint: Self::worker_hint(),
             solve: Box::new(move |input, workers| {
                 let start = time::Instant::now();
-                let solution = Self::solve(input, workers);
+                let (solution, stages) = Self::solve_staged(input, workers);
                 let time = time::Instant::now() - start;
-                (solution, time)
+                (solution, time, stages)
             }),
             input: String::default(),
             example: Self::example(),
+            run_example: false,
+            timeout: Self::timeout(),
+            cost: Self::cost(),
         }
     }
 }
@@ -58,13 +126,90 @@ where
         None
     }
 
-    fn example() -> Option<&'static str> {
+    fn example() -> Option<Example> {
         T::example()
     }
 
+    fn timeout() -> Option<Duration> {
+        T::timeout()
+    }
+
+    fn cost() -> Option<Duration> {
+        T::cost()
+    }
+
     fn solve(input: String, _: &mut WorkerGroup) -> (String, String) {
         T::solve(input)
     }
+
+    fn solve_staged(input: String, _: &mut WorkerGroup) -> ((String, String), Option<Stages>) {
+        T::solve_staged(input)
+    }
+}
+
+impl<T> Challenge for T
+where
+    T: StagedChallenge,
+{
+    fn year() -> usize {
+        T::year()
+    }
+
+    fn day() -> usize {
+        T::day()
+    }
+
+    fn example() -> Option<Example> {
+        T::example()
+    }
+
+    fn timeout() -> Option<Duration> {
+        T::timeout()
+    }
+
+    fn cost() -> Option<Duration> {
+        T::cost()
+    }
+
+    fn solve(input: String) -> (String, String) {
+        <Self as Challenge>::solve_staged(input).0
+    }
+
+    fn solve_staged(input: String) -> ((String, String), Option<Stages>) {
+        let start = time::Instant::now();
+        let parsed = T::parse(input);
+        let parse = start.elapsed();
+
+        let start = time::Instant::now();
+        let fst = T::part1(&parsed);
+        let part1 = start.elapsed();
+
+        let start = time::Instant::now();
+        let snd = T::part2(parsed);
+        let part2 = start.elapsed();
+
+        (
+            (fst, snd),
+            Some(Stages {
+                parse,
+                part1,
+                part2,
+            }),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stages {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub expected: Option<(&'static str, &'static str)>,
 }
 
 pub struct ChallengeObject {
@@ -73,32 +218,211 @@ pub struct ChallengeObject {
     pub worker_hint: Option<usize>,
     pub solve: Solver,
     pub input: String,
-    pub example: Option<&'static str>,
+    pub example: Option<Example>,
+    pub run_example: bool,
+    pub timeout: Option<Duration>,
+    pub cost: Option<Duration>,
+}
+
+pub struct Registration(pub fn() -> ChallengeObject);
+
+inventory::collect!(Registration);
+
+pub fn registered() -> Vec<ChallengeObject> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .map(|registration| (registration.0)())
+        .collect()
+}
+
+pub struct ExampleResult {
+    pub solution: (String, String),
+    pub verdict: (Verdict, Verdict),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Solved,
+    TimedOut,
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "OK"),
+            Status::TimedOut => write!(f, "TIMEOUT"),
+            Status::Failed(_) => write!(f, "FAILED"),
+        }
+    }
 }
 
 pub struct ChallengeResult {
     pub year: usize,
     pub day: usize,
+    pub status: Status,
     pub solution: (String, String),
     pub duration: Duration,
+    pub stages: Option<Stages>,
+    pub memory: Option<AllocStats>,
+    pub workers: usize,
+    pub example: Option<ExampleResult>,
 }
 
 impl ChallengeObject {
-    pub fn solve(self, workers: &mut WorkerGroup) -> ChallengeResult {
-        let input = match self.example {
-            Some(example) => example.to_string(),
-            None => self.input,
-        };
+    pub fn solve(mut self, workers: &mut WorkerGroup) -> ChallengeResult {
+        let input = mem::take(&mut self.input);
+        self.solve_input(input, workers)
+    }
+
+    pub fn solve_cloned(&self, workers: &mut WorkerGroup) -> ChallengeResult {
+        self.solve_input(self.input.clone(), workers)
+    }
+
+    fn solve_input(&self, input: String, workers: &mut WorkerGroup) -> ChallengeResult {
+        let start = time::Instant::now();
+        let tracker = Tracker::new();
+        tracker.adopt(input.capacity());
+        let solved = catch_quietly(|| {
+            let solved = {
+                let _scope = tracker.enter();
+                (self.solve)(input, workers)
+            };
 
-        let (solution, duration) = (self.solve)(input, workers);
+            let example = self.example.filter(|_| self.run_example).map(|example| {
+                let (solution, _, _) = (self.solve)(example.input.to_string(), workers);
+                let expected = example.expected.unzip();
+
+                ExampleResult {
+                    verdict: (
+                        Verdict::check(expected.0, &solution.0),
+                        Verdict::check(expected.1, &solution.1),
+                    ),
+                    solution,
+                }
+            });
+
+            (solved, example)
+        });
+
+        let ((solution, duration, stages), example) = match solved {
+            Ok(solved) => solved,
+            Err(payload) => {
+                return ChallengeResult::failed(
+                    self.year,
+                    self.day,
+                    panic_message(payload),
+                    start.elapsed(),
+                    workers.available() + 1,
+                );
+            }
+        };
 
         ChallengeResult {
-            year: self.year,
-            day: self.day,
+            stages,
+            memory: tracker.stats(),
+            example,
+            ..ChallengeResult::new(
+                self.year,
+                self.day,
+                solution,
+                duration,
+                workers.available() + 1,
+            )
+        }
+    }
+}
+
+impl ChallengeResult {
+    pub fn new(
+        year: usize,
+        day: usize,
+        solution: (String, String),
+        duration: Duration,
+        workers: usize,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            status: Status::Solved,
             solution,
             duration,
+            stages: None,
+            memory: None,
+            workers,
+            example: None,
         }
     }
+
+    pub fn timed_out(year: usize, day: usize, timeout: Duration, workers: usize) -> Self {
+        Self {
+            status: Status::TimedOut,
+            ..Self::new(year, day, Default::default(), timeout, workers)
+        }
+    }
+
+    pub fn failed(
+        year: usize,
+        day: usize,
+        message: String,
+        duration: Duration,
+        workers: usize,
+    ) -> Self {
+        Self {
+            status: Status::Failed(message),
+            duration,
+            ..Self::timed_out(year, day, duration, workers)
+        }
+    }
+
+    pub fn solved(&self) -> bool {
+        self.status == Status::Solved
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+// solver panics are reported as failed results, so the default hook shouldn't print them too
+pub(crate) fn catch_quietly<T>(f: impl FnOnce() -> T) -> thread::Result<T> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                previous(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+    result
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+pub fn duplicates(challenges: &[ChallengeObject]) -> Vec<(usize, usize)> {
+    let mut seen = HashSet::new();
+    let mut duplicates = challenges
+        .iter()
+        .map(|c| (c.year, c.day))
+        .filter(|key| !seen.insert(*key))
+        .collect::<Vec<_>>();
+
+    duplicates.sort();
+    duplicates.dedup();
+    duplicates
 }
 
 #[macro_export]
@@ -128,19 +452,56 @@ macro_rules! workers {
     };
 }
 
+#[macro_export]
+macro_rules! timeout {
+    ($secs:expr) => {
+        fn timeout() -> Option<std::time::Duration> {
+            Some(std::time::Duration::from_secs_f64($secs as f64))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! cost {
+    ($secs:expr) => {
+        fn cost() -> Option<std::time::Duration> {
+            Some(std::time::Duration::from_secs_f64($secs as f64))
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! example {
     ($x:expr) => {
-        fn example() -> Option<&'static str> {
-            Some($x)
+        fn example() -> Option<$crate::challenge::Example> {
+            Some($crate::challenge::Example {
+                input: $x,
+                expected: None,
+            })
+        }
+    };
+
+    ($x:expr, $fst:expr, $snd:expr) => {
+        fn example() -> Option<$crate::challenge::Example> {
+            Some($crate::challenge::Example {
+                input: $x,
+                expected: Some(($fst, $snd)),
+            })
         }
     };
 }
 
 #[macro_export]
 macro_rules! aoc {
-    (year = $y:expr, day = $d:expr) => {
+    ($name:ident, year = $y:expr, day = $d:expr) => {
         fn year() -> usize {
+            // items can't name `Self`, so the registration takes the type explicitly
+            $crate::inventory::submit! {
+                $crate::challenge::Registration(
+                    <$name as $crate::challenge::ThreadedChallenge>::into_obj
+                )
+            }
+
             $y
         }
 
@@ -148,4 +509,63 @@ macro_rules! aoc {
             $d
         }
     };
+
+    ($name:ident, year = $y:expr, day = $d:expr, timeout = $t:expr) => {
+        $crate::aoc!($name, year = $y, day = $d);
+        $crate::timeout!($t);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Panics;
+
+    impl Challenge for Panics {
+        crate::aoc!(Panics, year = 2024, day = 1);
+
+        fn solve(input: String) -> (String, String) {
+            let fst = input.parse::<usize>().unwrap();
+            (fst.to_string(), "".to_string())
+        }
+    }
+
+    #[test]
+    fn panics_become_failures() {
+        let mut workers = WorkerGroup::new(0);
+
+        let mut challenge = Panics::into_obj();
+        challenge.input = "12".to_string();
+        assert!(challenge.solve_cloned(&mut workers).solved());
+
+        challenge.input = "twelve".to_string();
+        let result = challenge.solve(&mut workers);
+
+        match result.status {
+            Status::Failed(message) => assert!(message.contains("ParseIntError")),
+            status => panic!("unexpected status {}", status),
+        }
+        assert!(!QUIET.get());
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn dropped_input_stays_counted() {
+        use std::hint::black_box;
+
+        let mut workers = WorkerGroup::new(0);
+
+        let mut challenge = Panics::into_obj();
+        challenge.input = "1".repeat(1000);
+        challenge.solve = Box::new(|input, _| {
+            drop(input);
+            let len = black_box(vec![0u8; 600]).len();
+            ((len.to_string(), String::new()), Duration::ZERO, None)
+        });
+
+        let memory = challenge.solve(&mut workers).memory.unwrap();
+        assert_eq!(memory.bytes, 600 + 3);
+        assert_eq!(memory.peak, 1000);
+    }
 }
@@ -1,18 +1,75 @@
-mod notifier;
+mod parallel;
+mod scope;
 mod worker;
 
-use std::{mem, thread};
+use std::{
+    cmp,
+    collections::{HashMap, VecDeque},
+    sync::mpsc,
+    thread,
+    time::{self, Duration},
+};
 
-use crate::challenge::{ChallengeObject, ChallengeResult};
-use notifier::Notifier;
+use scopeguard::ScopeGuard;
+
+use crate::{
+    bench::{BenchOptions, BenchResult},
+    challenge::{ChallengeObject, ChallengeResult},
+};
+pub use parallel::*;
+pub use scope::*;
 pub use worker::*;
 
+const TICK: Duration = Duration::from_millis(100);
+
+pub enum Event<'a> {
+    Queued {
+        year: usize,
+        day: usize,
+    },
+    Started {
+        year: usize,
+        day: usize,
+        workers: &'a [usize],
+    },
+    Finished(&'a ChallengeResult),
+    Tick,
+    Done,
+}
+
+type Observer = Box<dyn FnMut(Event<'_>)>;
+
+struct Completion {
+    id: usize,
+    worker: Worker,
+    workers: WorkerGroup,
+    result: ChallengeResult,
+}
+
+struct Scheduled {
+    year: usize,
+    day: usize,
+    workers: usize,
+    timeout: Option<Duration>,
+    start: time::Instant,
+}
+
+impl Scheduled {
+    fn deadline(&self) -> Option<time::Instant> {
+        self.timeout.map(|timeout| self.start + timeout)
+    }
+}
+
 pub struct AocExecutor {
     total: usize,
     worker_group: WorkerGroup,
-    scheduled: Vec<OwnedHandle<(ChallengeResult, WorkerGroup)>>,
-    notifier: Notifier,
-    results: Vec<ChallengeResult>,
+    queued: VecDeque<ChallengeObject>,
+    scheduled: HashMap<usize, Scheduled>,
+    next_id: usize,
+    sender: mpsc::Sender<Completion>,
+    receiver: mpsc::Receiver<Completion>,
+    finished: VecDeque<ChallengeResult>,
+    observer: Option<Observer>,
 }
 
 impl AocExecutor {
@@ -22,88 +79,509 @@ impl AocExecutor {
     }
 
     pub fn with_workers(workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
         Self {
             total: workers,
             worker_group: WorkerGroup::new(workers),
-            scheduled: Vec::new(),
-            notifier: Notifier::new(),
-            results: Vec::new(),
+            queued: VecDeque::new(),
+            scheduled: HashMap::new(),
+            next_id: 0,
+            sender,
+            receiver,
+            finished: VecDeque::new(),
+            observer: None,
         }
     }
 
-    pub fn run_one(&mut self, challenge: ChallengeObject) -> ChallengeResult {
-        let count = (challenge.worker_hint.unwrap_or(0) + 1).min(self.total);
-        let mut workers = self.wait_for_workers(count);
+    pub fn workers(&self) -> usize {
+        self.total
+    }
 
-        challenge.solve(&mut workers)
+    pub fn observe(&mut self, observer: impl FnMut(Event<'_>) + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    pub fn run_one(&mut self, challenge: ChallengeObject) -> ChallengeResult {
+        self.run_all(vec![challenge]).pop().unwrap()
     }
 
     pub fn run_all(&mut self, challenges: Vec<ChallengeObject>) -> Vec<ChallengeResult> {
-        for challenge in challenges.into_iter() {
-            self.run(challenge);
+        self.stream(challenges).collect()
+    }
+
+    pub fn stream(&mut self, mut challenges: Vec<ChallengeObject>) -> Results<'_> {
+        // longest expected first, so a slow day doesn't start last and stretch the whole run,
+        // and days without a cost could be just as slow, so they go before all of them
+        challenges.sort_by_key(|c| cmp::Reverse((c.cost.is_none(), c.cost, c.worker_hint)));
+
+        for challenge in challenges.iter() {
+            self.emit(Event::Queued {
+                year: challenge.year,
+                day: challenge.day,
+            });
         }
 
-        self.join_all();
+        self.queued.extend(challenges);
 
-        mem::take(&mut self.results)
+        Results {
+            executor: self,
+            done: false,
+        }
     }
 
-    fn run(&mut self, challenge: ChallengeObject) {
-        let count = (challenge.worker_hint.unwrap_or(0) + 1).min(self.total);
-        let mut workers = self.wait_for_workers(count);
+    pub fn bench(
+        &mut self,
+        challenge: ChallengeObject,
+        options: &BenchOptions,
+    ) -> Result<BenchResult, Box<ChallengeResult>> {
+        let (year, day, timeout) = (challenge.year, challenge.day, challenge.timeout);
+        let count = self.workers_for(&challenge);
+        let mut workers = self
+            .worker_group
+            .take(count)
+            .expect("workers are only busy while a run is in progress");
+
+        let (sender, receiver) = mpsc::channel();
+        let (returned_sender, returned) = oneshot::channel();
+        let (warmup, runs, budget) = (options.warmup, options.runs.max(1), options.budget);
 
-        let notifier = self.notifier.clone();
-        let handle = workers.take_one().unwrap().run_owned(move || {
-            let result = challenge.solve(&mut workers);
+        workers
+            .take_one()
+            .unwrap()
+            .execute_with_self(move |worker| {
+                // stops at the first failure, or once the caller stopped listening after a timeout
+                let run = |workers: &mut WorkerGroup| {
+                    let result = challenge.solve_cloned(workers);
+                    let solved = result.solved();
+                    sender.send(result).is_ok() && solved
+                };
 
-            let _guard = scopeguard::guard(notifier, |notifier| {
-                notifier.signal();
+                if (0..warmup).all(|_| run(&mut workers)) {
+                    let start = time::Instant::now();
+                    for _ in 0..runs {
+                        if !run(&mut workers)
+                            || budget.is_some_and(|budget| start.elapsed() >= budget)
+                        {
+                            break;
+                        }
+                    }
+                }
+
+                drop(sender);
+                let _ = returned_sender.send((worker, workers));
             });
 
-            (result, workers)
-        });
+        let mut samples = Vec::with_capacity(runs);
+        let mut failure = None;
+        for run in 0.. {
+            let received = match timeout {
+                Some(timeout) => receiver.recv_timeout(timeout),
+                None => receiver.recv().map_err(mpsc::RecvTimeoutError::from),
+            };
+
+            match received {
+                Ok(result) if !result.solved() => failure = Some(result),
+                Ok(result) if run >= warmup => samples.push(result.duration),
+                Ok(_) => {}
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    // same as a timed out run, the stuck thread is left behind and replaced
+                    self.worker_group
+                        .extend((0..count).map(|_| Worker::spawn()));
+                    return Err(Box::new(ChallengeResult::timed_out(
+                        year,
+                        day,
+                        timeout.unwrap(),
+                        count,
+                    )));
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
 
-        self.scheduled.push(handle);
+        let mut returned: Vec<_> = returned
+            .recv()
+            .map(|(worker, workers)| {
+                Some(worker)
+                    .into_iter()
+                    .chain(workers)
+                    .map(respawn)
+                    .collect()
+            })
+            .unwrap_or_default();
+        returned.extend((returned.len()..count).map(|_| Worker::spawn()));
+        self.worker_group.extend(returned.into_iter());
+
+        match failure {
+            Some(result) => Err(Box::new(result)),
+            None => Ok(BenchResult::from_samples(year, day, samples)),
+        }
+    }
+
+    fn workers_for(&self, challenge: &ChallengeObject) -> usize {
+        (challenge.worker_hint.unwrap_or(0) + 1).min(self.total)
     }
 
-    fn wait_for_workers(&mut self, count: usize) -> WorkerGroup {
+    fn emit(&mut self, event: Event<'_>) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer(event);
+        }
+    }
+
+    fn finish(&mut self, result: ChallengeResult) {
+        self.emit(Event::Finished(&result));
+        self.finished.push_back(result);
+    }
+
+    fn next_result(&mut self) -> Option<ChallengeResult> {
         loop {
-            if let Some(workers) = self.worker_group.take(count) {
-                return workers;
+            self.start_queued();
+
+            if let Some(result) = self.finished.pop_front() {
+                return Some(result);
+            }
+
+            if self.scheduled.is_empty() {
+                return None;
             }
 
-            self.notifier.wait();
-            self.join();
+            self.wait();
         }
     }
 
-    fn join(&mut self) {
-        self.scheduled.retain_mut(|handle| match handle.try_join() {
-            Some((main_worker, (result, extra_workers))) => {
-                self.results.push(result);
-                self.worker_group
-                    .extend(Some(main_worker).into_iter().chain(extra_workers));
+    fn start_queued(&mut self) {
+        while let Some(challenge) = self.queued.front() {
+            let Some(workers) = self.worker_group.take(self.workers_for(challenge)) else {
+                break;
+            };
 
-                false
-            }
+            let challenge = self.queued.pop_front().unwrap();
+            self.start(challenge, workers);
+        }
+    }
 
-            None => true,
+    fn start(&mut self, challenge: ChallengeObject, mut workers: WorkerGroup) {
+        let (year, day, timeout) = (challenge.year, challenge.day, challenge.timeout);
+        let count = workers.available();
+
+        self.emit(Event::Started {
+            year,
+            day,
+            workers: &workers.ids(),
         });
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let start = time::Instant::now();
+        let sender = self.sender.clone();
+        workers
+            .take_one()
+            .unwrap()
+            .execute_with_self(move |worker| {
+                // hand the worker back even if the solver manages to unwind past its own panic guard
+                let guard =
+                    scopeguard::guard_on_unwind((worker, sender), move |(worker, sender)| {
+                        let _ = sender.send(Completion {
+                            id,
+                            worker,
+                            workers: WorkerGroup::new(0),
+                            result: ChallengeResult::failed(
+                                year,
+                                day,
+                                "the worker thread panicked".to_string(),
+                                start.elapsed(),
+                                count,
+                            ),
+                        });
+                    });
+
+                let result = challenge.solve(&mut workers);
+
+                let (worker, sender) = ScopeGuard::into_inner(guard);
+                let _ = sender.send(Completion {
+                    id,
+                    worker,
+                    workers,
+                    result,
+                });
+            });
+
+        self.scheduled.insert(
+            id,
+            Scheduled {
+                year,
+                day,
+                workers: count,
+                timeout,
+                start,
+            },
+        );
     }
 
-    fn join_all(&mut self) {
-        for handle in self.scheduled.drain(..) {
-            if let Some((main_worker, (result, extra_workers))) = handle.join() {
-                self.results.push(result);
-                self.worker_group
-                    .extend(Some(main_worker).into_iter().chain(extra_workers));
-            }
+    fn wait(&mut self) {
+        let tick = self.observer.is_some().then(|| time::Instant::now() + TICK);
+
+        let completion = match self
+            .scheduled
+            .values()
+            .filter_map(Scheduled::deadline)
+            .chain(tick)
+            .min()
+        {
+            Some(deadline) => self
+                .receiver
+                .recv_timeout(deadline.saturating_duration_since(time::Instant::now()))
+                .ok(),
+            None => self.receiver.recv().ok(),
+        };
+
+        for completion in completion
+            .into_iter()
+            .chain(self.receiver.try_iter().collect::<Vec<_>>())
+        {
+            self.complete(completion);
+        }
+
+        self.expire();
+        self.emit(Event::Tick);
+    }
+
+    fn complete(&mut self, completion: Completion) {
+        let Completion {
+            id,
+            worker,
+            workers,
+            result,
+        } = completion;
+
+        // a challenge that already timed out had its workers replaced, so late ones are dropped
+        let Some(scheduled) = self.scheduled.remove(&id) else {
+            return;
+        };
+
+        let mut returned = Some(worker)
+            .into_iter()
+            .chain(workers)
+            .map(respawn)
+            .collect::<Vec<_>>();
+        returned.extend((returned.len()..scheduled.workers).map(|_| Worker::spawn()));
+
+        self.worker_group.extend(returned.into_iter());
+        self.finish(result);
+    }
+
+    fn expire(&mut self) {
+        let now = time::Instant::now();
+        let expired = self
+            .scheduled
+            .iter()
+            .filter(|(_, scheduled)| scheduled.deadline().is_some_and(|deadline| deadline <= now))
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+
+        for id in expired {
+            let scheduled = self.scheduled.remove(&id).unwrap();
+
+            // the stuck thread can't be stopped, so leave it behind and replace its workers
+            self.worker_group
+                .extend((0..scheduled.workers).map(|_| Worker::spawn()));
+
+            self.finish(ChallengeResult::timed_out(
+                scheduled.year,
+                scheduled.day,
+                scheduled.timeout.unwrap(),
+                scheduled.workers,
+            ));
         }
     }
 }
 
+pub struct Results<'a> {
+    executor: &'a mut AocExecutor,
+    done: bool,
+}
+
+impl Iterator for Results<'_> {
+    type Item = ChallengeResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.executor.next_result();
+
+        if result.is_none() && !self.done {
+            self.done = true;
+            self.executor.emit(Event::Done);
+        }
+
+        result
+    }
+}
+
+impl Drop for Results<'_> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+fn respawn(worker: Worker) -> Worker {
+    match worker.is_alive() {
+        true => worker,
+        false => Worker::spawn(),
+    }
+}
+
 impl Default for AocExecutor {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::challenge::Status;
+
+    fn challenge(day: usize, sleep: Duration, timeout: Option<Duration>) -> ChallengeObject {
+        ChallengeObject {
+            year: 2024,
+            day,
+            worker_hint: None,
+            solve: Box::new(move |_, _| {
+                thread::sleep(sleep);
+                (("done".to_string(), String::new()), sleep, None)
+            }),
+            input: String::new(),
+            example: None,
+            run_example: false,
+            timeout,
+            cost: None,
+        }
+    }
+
+    #[test]
+    fn failed_challenges() {
+        let mut executor = AocExecutor::with_workers(2);
+
+        let mut failing = challenge(1, Duration::ZERO, None);
+        failing.solve = Box::new(|_, _| panic!("boom"));
+
+        let mut results = executor.run_all(vec![
+            failing,
+            challenge(2, Duration::from_millis(10), None),
+            challenge(3, Duration::from_millis(10), None),
+        ]);
+        results.sort_by_key(|r| r.day);
+
+        assert_eq!(results[0].status, Status::Failed("boom".to_string()));
+        assert!(results[1..].iter().all(|r| r.solved()));
+        assert_eq!(executor.worker_group.available(), 2);
+    }
+
+    #[test]
+    fn streamed_results() {
+        let mut executor = AocExecutor::with_workers(2);
+
+        let mut results = executor.stream(vec![
+            challenge(1, Duration::from_millis(200), None),
+            challenge(2, Duration::from_millis(10), None),
+            challenge(3, Duration::from_millis(10), None),
+        ]);
+
+        assert_eq!(results.next().map(|r| r.day), Some(2));
+        assert_eq!(results.next().map(|r| r.day), Some(3));
+        assert_eq!(results.next().map(|r| r.day), Some(1));
+        assert!(results.next().is_none());
+        drop(results);
+
+        assert_eq!(executor.worker_group.available(), 2);
+    }
+
+    #[test]
+    fn longest_first() {
+        let mut executor = AocExecutor::with_workers(1);
+
+        let mut short = challenge(1, Duration::ZERO, None);
+        short.cost = Some(Duration::from_millis(1));
+        let mut long = challenge(2, Duration::ZERO, None);
+        long.cost = Some(Duration::from_millis(10));
+        let unknown = challenge(3, Duration::ZERO, None);
+        let mut threaded = challenge(4, Duration::ZERO, None);
+        threaded.cost = Some(Duration::from_millis(10));
+        threaded.worker_hint = Some(2);
+
+        let results = executor.run_all(vec![short, long, unknown, threaded]);
+        let days = results.iter().map(|r| r.day).collect::<Vec<_>>();
+
+        assert_eq!(days, [3, 4, 2, 1]);
+    }
+
+    #[test]
+    fn timed_out_challenges() {
+        let mut executor = AocExecutor::with_workers(2);
+
+        let mut results = executor.run_all(vec![
+            challenge(1, Duration::from_secs(5), Some(Duration::from_millis(50))),
+            challenge(2, Duration::from_millis(10), None),
+            challenge(3, Duration::from_millis(10), Some(Duration::from_secs(5))),
+            challenge(4, Duration::from_millis(10), None),
+        ]);
+        results.sort_by_key(|r| r.day);
+
+        assert_eq!(results.len(), 4);
+        assert!(!results[0].solved());
+        assert_eq!(results[0].duration, Duration::from_millis(50));
+        assert!(
+            results[1..]
+                .iter()
+                .all(|r| r.solved() && r.solution.0 == "done")
+        );
+        assert_eq!(executor.worker_group.available(), 2);
+    }
+
+    #[test]
+    fn bench_stops_on_failure() {
+        let mut executor = AocExecutor::with_workers(2);
+        let options = BenchOptions {
+            warmup: 1,
+            runs: 3,
+            budget: None,
+        };
+
+        let result = executor
+            .bench(challenge(1, Duration::from_millis(1), None), &options)
+            .map_err(|result| result.status)
+            .unwrap();
+        assert_eq!(result.runs, 3);
+
+        let runs = Arc::new(AtomicUsize::new(0));
+        let mut failing = challenge(2, Duration::ZERO, None);
+        let counter = Arc::clone(&runs);
+        failing.solve = Box::new(move |_, _| match counter.fetch_add(1, Ordering::Relaxed) {
+            0 => (("done".to_string(), String::new()), Duration::ZERO, None),
+            _ => panic!("boom"),
+        });
+
+        let failure = executor.bench(failing, &options).unwrap_err();
+        assert_eq!(failure.status, Status::Failed("boom".to_string()));
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+        assert_eq!(executor.worker_group.available(), 2);
+    }
+
+    #[test]
+    fn bench_times_out() {
+        let mut executor = AocExecutor::with_workers(2);
+
+        let looping = challenge(1, Duration::from_secs(5), Some(Duration::from_millis(50)));
+        let failure = executor
+            .bench(looping, &BenchOptions::default())
+            .unwrap_err();
+
+        assert_eq!(failure.status, Status::TimedOut);
+        assert_eq!(executor.worker_group.available(), 2);
+    }
+}
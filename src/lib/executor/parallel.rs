@@ -0,0 +1,252 @@
+use std::{
+    ops::Range,
+    panic,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+};
+
+use super::WorkerGroup;
+
+pub trait Partition: Sized + Send {
+    type Item;
+
+    fn size(&self) -> usize;
+    fn split(self, mid: usize) -> (Self, Self);
+    fn into_items(self) -> impl Iterator<Item = Self::Item>;
+}
+
+impl<'a, T: Sync> Partition for &'a [T] {
+    type Item = &'a T;
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn split(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at(self, mid)
+    }
+
+    fn into_items(self) -> impl Iterator<Item = Self::Item> {
+        self.iter()
+    }
+}
+
+impl Partition for Range<usize> {
+    type Item = usize;
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn split(self, mid: usize) -> (Self, Self) {
+        let mid = self.start + mid;
+        (self.start..mid, mid..self.end)
+    }
+
+    fn into_items(self) -> impl Iterator<Item = Self::Item> {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl WorkerGroup {
+    pub fn race<T, F>(&mut self, f: F) -> Option<T>
+    where
+        T: Send,
+        F: Fn(&CancelToken) -> Option<T> + Sync,
+    {
+        let tasks = self.available().max(1);
+        let token = CancelToken::new();
+        let (sender, receiver) = mpsc::channel();
+
+        let (f, token) = (&f, &token);
+        self.scope(|scope| {
+            for _ in 0..tasks {
+                let sender = sender.clone();
+                scope.spawn(move || {
+                    let _ = sender.send(f(token));
+                });
+            }
+
+            drop(sender);
+
+            // the remaining tasks poll the token, so the scope doesn't wait on them for long
+            let winner = receiver.iter().flatten().next();
+            token.cancel();
+            winner
+        })
+    }
+
+    pub fn par_chunks<P, R, F>(&mut self, items: P, f: F) -> Vec<R>
+    where
+        P: Partition,
+        R: Send,
+        F: Fn(P) -> R + Sync,
+    {
+        let parts = self.available().max(1).min(items.size().max(1));
+
+        let mut chunks = Vec::with_capacity(parts);
+        let mut rest = items;
+        for part in (1..=parts).rev() {
+            let size = rest.size().div_ceil(part);
+            let (chunk, tail) = rest.split(size);
+            chunks.push(chunk);
+            rest = tail;
+        }
+
+        let f = &f;
+        self.scope(|scope| {
+            let handles = chunks
+                .into_iter()
+                .map(|chunk| scope.spawn(move || f(chunk)))
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                })
+                .collect()
+        })
+    }
+
+    pub fn par_map<P, R, F>(&mut self, items: P, f: F) -> Vec<R>
+    where
+        P: Partition,
+        R: Send,
+        F: Fn(P::Item) -> R + Sync,
+    {
+        self.par_chunks(items, |chunk| {
+            chunk.into_items().map(&f).collect::<Vec<_>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    pub fn par_for_each<P, F>(&mut self, items: P, f: F)
+    where
+        P: Partition,
+        F: Fn(P::Item) + Sync,
+    {
+        self.par_chunks(items, |chunk| chunk.into_items().for_each(&f));
+    }
+
+    pub fn par_reduce<P, R, M, C>(&mut self, items: P, map: M, combine: C) -> Option<R>
+    where
+        P: Partition,
+        R: Send,
+        M: Fn(P::Item) -> R + Sync,
+        C: Fn(R, R) -> R + Sync,
+    {
+        self.par_chunks(items, |chunk| chunk.into_items().map(&map).reduce(&combine))
+            .into_iter()
+            .flatten()
+            .reduce(&combine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicUsize, thread};
+
+    use super::*;
+
+    #[test]
+    fn par_map_keeps_order() {
+        let mut workers = WorkerGroup::new(3);
+
+        let values = (0..100).collect::<Vec<usize>>();
+        let squares = workers.par_map(values.as_slice(), |x| x * x);
+
+        assert_eq!(squares, values.iter().map(|x| x * x).collect::<Vec<_>>());
+        assert_eq!(workers.par_map(0..5, |x| x + 1), [1, 2, 3, 4, 5]);
+        assert_eq!(workers.par_map(0..0, |x| x), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn par_map_keeps_panics() {
+        let mut workers = WorkerGroup::new(2);
+
+        let values = [1, 2, 3];
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            workers.par_map(0..3, |i| values[i + 5])
+        }));
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("index out of bounds"));
+        assert_eq!(workers.par_map(0..3, |i| values[i]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn par_chunks_split_evenly() {
+        let mut workers = WorkerGroup::new(3);
+
+        assert_eq!(
+            workers.par_chunks(0..10, |chunk| chunk),
+            [0..4, 4..7, 7..10]
+        );
+        assert_eq!(workers.par_chunks(0..2, |chunk| chunk), [0..1, 1..2]);
+
+        let inline = WorkerGroup::new(0).par_chunks(0..2, |chunk| chunk.len());
+        assert_eq!(inline, [2]);
+    }
+
+    #[test]
+    fn par_for_each_and_reduce() {
+        let mut workers = WorkerGroup::new(2);
+
+        let count = AtomicUsize::new(0);
+        workers.par_for_each(0..1000, |x| {
+            count.fetch_add(x, Ordering::Relaxed);
+        });
+        assert_eq!(count.into_inner(), 499500);
+
+        let words = ["a", "b", "c", "d", "e"];
+        let joined = workers.par_reduce(&words[..], |w| w.to_string(), |a, b| a + &b);
+        assert_eq!(joined.as_deref(), Some("abcde"));
+        assert_eq!(workers.par_reduce(0..0, |x| x, |a, b| a + b), None);
+    }
+
+    #[test]
+    fn race_first_wins() {
+        let mut workers = WorkerGroup::new(3);
+
+        let started = AtomicUsize::new(0);
+        let winner = workers.race(|cancel| match started.fetch_add(1, Ordering::Relaxed) {
+            0 => Some(42),
+            _ => {
+                while !cancel.is_cancelled() {
+                    thread::yield_now();
+                }
+                None
+            }
+        });
+
+        assert_eq!(winner, Some(42));
+        assert_eq!(workers.race(|_| None::<usize>), None);
+        assert_eq!(WorkerGroup::new(0).race(|_| Some(1)), Some(1));
+    }
+}
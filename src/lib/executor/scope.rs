@@ -0,0 +1,215 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    marker::PhantomData,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+};
+
+use super::{Worker, WorkerGroup, worker::lost_worker};
+use crate::memory::Tracker;
+
+// a panicked task leaves its payload here, for its handle or else for the scope to resume
+type PanicSlot = Arc<Mutex<Option<Box<dyn Any + Send>>>>;
+
+#[derive(Default)]
+struct ScopeState {
+    running: Mutex<usize>,
+    done: Condvar,
+    panics: Mutex<Vec<PanicSlot>>,
+}
+
+impl ScopeState {
+    fn start(&self) {
+        *self.running.lock().unwrap() += 1;
+    }
+
+    fn finish(&self) {
+        let mut running = self.running.lock().unwrap();
+        *running -= 1;
+        if *running == 0 {
+            self.done.notify_all();
+        }
+    }
+
+    fn wait(&self) {
+        let running = self.running.lock().unwrap();
+        let _running = self
+            .done
+            .wait_while(running, |running| *running > 0)
+            .unwrap();
+    }
+
+    fn unjoined_panic(&self) -> Option<Box<dyn Any + Send>> {
+        self.panics
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|slot| slot.lock().unwrap().take())
+    }
+}
+
+pub struct Scope<'scope, 'env: 'scope> {
+    workers: Vec<&'scope Worker>,
+    next: Cell<usize>,
+    state: Arc<ScopeState>,
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope> Scope<'scope, '_> {
+    pub fn spawn<F, T>(&'scope self, task: F) -> ScopedHandle<'scope, T>
+    where
+        F: FnOnce() -> T + Send + 'scope,
+        T: Send + 'scope,
+    {
+        if self.workers.is_empty() {
+            return ScopedHandle {
+                result: Pending::Ready(task()),
+                scope: PhantomData,
+            };
+        }
+
+        let worker = self.workers[self.next.get() % self.workers.len()];
+        self.next.set(self.next.get() + 1);
+
+        let (signal_sender, signal_receiver) = oneshot::channel();
+        let slot = PanicSlot::default();
+        self.state.panics.lock().unwrap().push(Arc::clone(&slot));
+
+        let state = Arc::clone(&self.state);
+        let tracker = Tracker::current();
+        let task_slot = Arc::clone(&slot);
+        let work: Box<dyn FnOnce() + Send + 'scope> = Box::new(move || {
+            let _guard = scopeguard::guard(state, |state| state.finish());
+            let _scope = tracker.enter();
+            match panic::catch_unwind(AssertUnwindSafe(task)) {
+                Ok(value) => {
+                    let _ = signal_sender.send(value);
+                }
+                Err(payload) => *task_slot.lock().unwrap() = Some(payload),
+            }
+        });
+
+        // SAFETY: `WorkerGroup::scope` waits for every spawned task to finish before it
+        // returns, so nothing borrowed for 'scope is used after it ends
+        let work: Box<dyn FnOnce() + Send + 'static> = unsafe { mem::transmute(work) };
+
+        self.state.start();
+        if let Err(work) = worker.submit(work) {
+            work();
+        }
+
+        ScopedHandle {
+            result: Pending::Running(signal_receiver, slot),
+            scope: PhantomData,
+        }
+    }
+}
+
+enum Pending<T> {
+    Running(oneshot::Receiver<T>, PanicSlot),
+    Ready(T),
+}
+
+pub struct ScopedHandle<'scope, T> {
+    result: Pending<T>,
+    scope: PhantomData<&'scope ()>,
+}
+
+impl<T> ScopedHandle<'_, T> {
+    pub fn join(self) -> thread::Result<T> {
+        match self.result {
+            Pending::Running(signal, slot) => signal
+                .recv()
+                .map_err(|_| slot.lock().unwrap().take().unwrap_or_else(lost_worker)),
+            Pending::Ready(value) => Ok(value),
+        }
+    }
+}
+
+impl WorkerGroup {
+    pub fn scope<'env, F, T>(&mut self, f: F) -> T
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+    {
+        let scope = Scope {
+            workers: self.iter().collect(),
+            next: Cell::new(0),
+            state: Arc::default(),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+        scope.state.wait();
+
+        // like std scoped threads, a panic nobody joined takes down the whole scope
+        match (result, scope.state.unjoined_panic()) {
+            (Err(payload), _) | (Ok(_), Some(payload)) => panic::resume_unwind(payload),
+            (Ok(result), None) => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoped_borrows() {
+        let mut workers = WorkerGroup::new(3);
+
+        let values = (1..=100).collect::<Vec<usize>>();
+        let mut sums = vec![0; 4];
+
+        let total = workers.scope(|scope| {
+            for (chunk, sum) in values.chunks(25).zip(sums.iter_mut()) {
+                scope.spawn(move || *sum = chunk.iter().sum());
+            }
+
+            let handle = scope.spawn(|| values.len());
+            handle.join().ok().unwrap()
+        });
+
+        assert_eq!(total, 100);
+        assert_eq!(sums, [325, 950, 1575, 2200]);
+        assert_eq!(workers.available(), 3);
+    }
+
+    #[test]
+    fn scoped_inline() {
+        let mut workers = WorkerGroup::new(0);
+
+        let values = [1, 2, 3];
+        let sum = workers.scope(|scope| scope.spawn(|| values.iter().sum::<i32>()).join().ok());
+
+        assert_eq!(sum, Some(6));
+    }
+
+    #[test]
+    fn scoped_panics() {
+        let mut workers = WorkerGroup::new(2);
+
+        let joined = workers.scope(|scope| {
+            let handle = scope.spawn(|| -> usize { panic!("joined") });
+            handle.join().unwrap_err()
+        });
+        assert_eq!(joined.downcast_ref::<&str>(), Some(&"joined"));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            workers.scope(|scope| {
+                scope.spawn(|| -> usize { panic!("unjoined") });
+            })
+        }));
+
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"unjoined"));
+        assert_eq!(
+            workers.scope(|scope| scope.spawn(|| 2).join().ok()),
+            Some(2)
+        );
+    }
+}
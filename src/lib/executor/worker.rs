@@ -1,5 +1,21 @@
 use oneshot;
-use std::{sync, thread};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        self,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+use crate::{challenge, memory::Tracker};
+
+static NEXT_WORKER_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub(super) fn lost_worker() -> Box<dyn Any + Send> {
+    Box::new("the worker thread died")
+}
 
 pub enum Task {
     Execute(Box<dyn FnOnce() + Send + 'static>),
@@ -7,6 +23,7 @@ pub enum Task {
 }
 
 pub struct Worker {
+    id: usize,
     thread: Option<thread::JoinHandle<()>>,
     sender: sync::mpsc::Sender<Task>,
 }
@@ -16,11 +33,15 @@ impl Worker {
         let (task_sender, task_receiver) = sync::mpsc::channel();
 
         Self {
+            id: NEXT_WORKER_ID.fetch_add(1, Ordering::Relaxed),
             sender: task_sender,
             thread: Some(thread::spawn(move || {
                 while let Ok(task) = task_receiver.recv() {
                     match task {
-                        Task::Execute(work) => work(),
+                        // tasks with a handle send their panic back, this only guards the loop
+                        Task::Execute(work) => {
+                            let _ = challenge::catch_quietly(work);
+                        }
                         Task::Exit => break,
                     }
                 }
@@ -35,8 +56,10 @@ impl Worker {
     {
         let (signal_sender, signal_receiver) = oneshot::channel();
 
-        let work = Task::Execute(Box::new(|| {
-            let _ = signal_sender.send(task());
+        let tracker = Tracker::current();
+        let work = Task::Execute(Box::new(move || {
+            let _scope = tracker.enter();
+            let _ = signal_sender.send(panic::catch_unwind(AssertUnwindSafe(task)));
         }));
 
         self.sender.send(work).expect("the worker thread died");
@@ -61,8 +84,10 @@ impl Worker {
     {
         let (signal_sender, signal_receiver) = oneshot::channel();
 
-        let work = Task::Execute(Box::new(|| {
-            let _ = signal_sender.send(task());
+        let tracker = Tracker::current();
+        let work = Task::Execute(Box::new(move || {
+            let _scope = tracker.enter();
+            let _ = signal_sender.send(panic::catch_unwind(AssertUnwindSafe(task)));
         }));
 
         self.sender.send(work).expect("the worker thread died");
@@ -78,44 +103,86 @@ impl Worker {
     {
         self.run_owned(task)
     }
+
+    pub fn execute_with_self<F>(self, task: F)
+    where
+        F: FnOnce(Worker) + Send + 'static,
+    {
+        let sender = self.sender.clone();
+
+        let tracker = Tracker::current();
+        let work = Task::Execute(Box::new(move || {
+            let _scope = tracker.enter();
+            task(self);
+        }));
+
+        sender.send(work).expect("the worker thread died");
+    }
+
+    pub(super) fn submit(
+        &self,
+        work: Box<dyn FnOnce() + Send + 'static>,
+    ) -> Result<(), Box<dyn FnOnce() + Send + 'static>> {
+        self.sender
+            .send(Task::Execute(work))
+            .map_err(|err| match err.0 {
+                Task::Execute(work) => work,
+                Task::Exit => unreachable!(),
+            })
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
 }
 
 impl Drop for Worker {
     fn drop(&mut self) {
-        if self.sender.send(Task::Exit).is_ok() {
-            self.thread.take().unwrap().join().unwrap();
+        // a worker can be dropped by a task running on its own thread, which can't join itself
+        if self.sender.send(Task::Exit).is_ok()
+            && let Some(thread) = self.thread.take()
+            && thread.thread().id() != thread::current().id()
+        {
+            thread.join().unwrap();
         }
     }
 }
 
 pub struct OwnedHandle<T> {
-    inner: Option<(Worker, oneshot::Receiver<T>)>,
+    inner: Option<(Worker, oneshot::Receiver<thread::Result<T>>)>,
 }
 
 impl<T> OwnedHandle<T> {
-    pub fn try_join(&mut self) -> Option<(Worker, T)> {
+    pub fn try_join(&mut self) -> Option<thread::Result<(Worker, T)>> {
         let value = self.inner.as_ref()?.1.try_recv().ok()?;
         let worker = self.inner.take()?.0;
-        Some((worker, value))
+        Some(value.map(|value| (worker, value)))
     }
 
-    pub fn join(mut self) -> Option<(Worker, T)> {
-        let inner = self.inner.take()?;
-        Some((inner.0, inner.1.recv().ok()?))
+    pub fn join(mut self) -> thread::Result<(Worker, T)> {
+        let (worker, signal) = self.inner.take().ok_or_else(lost_worker)?;
+        let value = signal.recv().unwrap_or_else(|_| Err(lost_worker()))?;
+        Ok((worker, value))
     }
 }
 
 impl<T> Drop for OwnedHandle<T> {
     fn drop(&mut self) {
         if let Some(inner) = self.inner.take() {
-            inner.1.recv_ref().expect("the worker thread died");
+            let _ = inner.1.recv_ref();
         }
     }
 }
 
 pub struct RefHandle<'a, T> {
     worker: &'a mut Worker,
-    signal: Option<oneshot::Receiver<T>>,
+    signal: Option<oneshot::Receiver<thread::Result<T>>>,
 }
 
 impl<T> RefHandle<'_, T> {
@@ -123,8 +190,9 @@ impl<T> RefHandle<'_, T> {
         self.worker
     }
 
-    pub fn join(mut self) -> Option<T> {
-        self.signal.take().unwrap().recv().ok()
+    pub fn join(mut self) -> thread::Result<T> {
+        let signal = self.signal.take().unwrap();
+        signal.recv().unwrap_or_else(|_| Err(lost_worker()))
     }
 }
 
@@ -169,6 +237,10 @@ impl WorkerGroup {
         })
     }
 
+    pub fn ids(&self) -> Vec<usize> {
+        self.available.iter().map(Worker::id).collect()
+    }
+
     pub fn take_one(&mut self) -> Option<Worker> {
         self.available.pop()
     }
@@ -232,6 +304,34 @@ mod tests {
         assert_eq!(result, 2);
     }
 
+    #[test]
+    fn worker_survives_panic() {
+        let mut worker = super::Worker::spawn();
+
+        let handle = worker.run(|| -> usize { panic!("boom") });
+        let payload = handle.join().unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+
+        let handle = worker.run(|| 3);
+        assert_eq!(handle.join().ok(), Some(3));
+        assert!(worker.is_alive());
+    }
+
+    #[test]
+    fn worker_execute_with_self() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let worker = super::Worker::spawn();
+        let id = worker.id();
+        worker.execute_with_self(move |worker| sender.send(worker).unwrap());
+
+        let mut worker = receiver.recv().unwrap();
+        assert_eq!(worker.id(), id);
+        assert_eq!(worker.run(|| 4).join().ok(), Some(4));
+
+        worker.execute_with_self(drop);
+    }
+
     #[test]
     fn worker_drop_handles() {
         let mut worker = super::Worker::spawn();
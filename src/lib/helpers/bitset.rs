@@ -176,8 +176,8 @@ mod tests {
         b = b.set(7);
         b = b.set(80);
 
-        assert_eq!(b.get(80), true);
-        assert_eq!(b.get(72), false);
+        assert!(b.get(80));
+        assert!(!b.get(72));
     }
 
     #[test]
@@ -202,9 +202,9 @@ mod tests {
 
         let b = b1.union(b2);
 
-        assert_eq!(b.get(3), true);
-        assert_eq!(b.get(7), true);
-        assert_eq!(b.get(6), false);
+        assert!(b.get(3));
+        assert!(b.get(7));
+        assert!(!b.get(6));
         assert_eq!(b.count_bits(), 2);
     }
 
@@ -219,9 +219,9 @@ mod tests {
 
         let b = b1.intersect(b2);
 
-        assert_eq!(b.get(3), false);
-        assert_eq!(b.get(7), true);
-        assert_eq!(b.get(6), false);
+        assert!(!b.get(3));
+        assert!(b.get(7));
+        assert!(!b.get(6));
         assert_eq!(b.count_bits(), 1);
     }
 
@@ -143,12 +143,12 @@ impl<T: Clone + PartialEq> SegmentSequence<T> {
     {
         match self.binary_search(new.lo) {
             Err(idx) => {
-                if let Some(next) = self.inner.get_mut(idx) {
-                    if new.hi > next.lo {
-                        let (lower, upper) = new.split(next.lo).unwrap();
-                        return self.insert_helper(lower, f) || self.insert_helper(upper, f);
-                    }
-                };
+                if let Some(next) = self.inner.get_mut(idx)
+                    && new.hi > next.lo
+                {
+                    let (lower, upper) = new.split(next.lo).unwrap();
+                    return self.insert_helper(lower, f) || self.insert_helper(upper, f);
+                }
 
                 self.inner.insert(idx, new);
                 false
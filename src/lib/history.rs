@@ -0,0 +1,183 @@
+use std::{
+    io,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge::{ChallengeResult, Status},
+    inputs::FileCache,
+    util::into_io_error,
+};
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub year: usize,
+    pub day: usize,
+    pub status: String,
+    pub part1: String,
+    pub part2: String,
+    pub duration_ns: u64,
+}
+
+impl HistoryEntry {
+    pub fn new(result: &ChallengeResult, timestamp: u64, commit: Option<String>) -> Self {
+        Self {
+            timestamp,
+            commit,
+            year: result.year,
+            day: result.day,
+            status: result.status.to_string(),
+            part1: result.solution.0.clone(),
+            part2: result.solution.1.clone(),
+            duration_ns: result.duration.as_nanos() as u64,
+        }
+    }
+
+    pub fn solved(&self) -> bool {
+        self.status == Status::Solved.to_string()
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    pub fn load(cache: &FileCache) -> io::Result<Self> {
+        let contents = match cache.get(HISTORY_FILE) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(into_io_error))
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn append(cache: &FileCache, results: &[ChallengeResult]) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(into_io_error)?
+            .as_secs();
+        let commit = git_commit();
+
+        let mut lines = String::new();
+        for result in results {
+            let entry = HistoryEntry::new(result, timestamp, commit.clone());
+            lines.push_str(&serde_json::to_string(&entry).map_err(into_io_error)?);
+            lines.push('\n');
+        }
+
+        cache.append(HISTORY_FILE, lines)
+    }
+
+    pub fn entries(&self, year: usize, day: usize) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.year == year && entry.day == day)
+    }
+}
+
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+
+    let commit = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !commit.trim().is_empty()).then(|| commit.trim().to_string())
+}
+
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, secs) = (timestamp / 86400, timestamp % 86400);
+
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    fn result(day: usize, micros: u64) -> ChallengeResult {
+        let solution = ("1".to_string(), "2".to_string());
+        ChallengeResult::new(2024, day, solution, Duration::from_micros(micros), 1)
+    }
+
+    #[test]
+    fn append_and_query() {
+        let root = env::temp_dir().join(format!("aoc_history_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = FileCache::open(&root).unwrap();
+
+        History::append(&cache, &[result(1, 10), result(2, 20)]).unwrap();
+        History::append(&cache, &[result(1, 30)]).unwrap();
+        History::append(
+            &cache,
+            &[ChallengeResult::timed_out(
+                2024,
+                2,
+                Duration::from_secs(1),
+                1,
+            )],
+        )
+        .unwrap();
+
+        let history = History::load(&cache).unwrap();
+        let entries = history.entries(2024, 1).collect::<Vec<_>>();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].duration(), Duration::from_micros(10));
+        assert_eq!(entries[1].duration(), Duration::from_micros(30));
+        assert!(entries[1].solved());
+        assert!(!history.entries(2024, 2).last().unwrap().solved());
+        assert_eq!(history.entries(2024, 3).count(), 0);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_011_200 + 3_660), "2024-12-01 01:01");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
@@ -1,4 +1,8 @@
-use std::{fs, io, path};
+use std::{
+    fs,
+    io::{self, Write},
+    path,
+};
 
 pub struct FileCache {
     root: path::PathBuf,
@@ -28,4 +32,33 @@ impl FileCache {
         let path = self.root.join(file.into());
         fs::write(path, contents)
     }
+
+    pub fn append(
+        &self,
+        file: impl Into<path::PathBuf>,
+        contents: impl AsRef<[u8]>,
+    ) -> io::Result<()> {
+        let path = self.root.join(file.into());
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(contents.as_ref())
+    }
+
+    pub fn contains(&self, file: impl Into<path::PathBuf>) -> bool {
+        self.root.join(file.into()).is_file()
+    }
+
+    pub fn remove(&self, file: impl Into<path::PathBuf>) -> io::Result<()> {
+        fs::remove_file(self.root.join(file.into()))
+    }
+
+    pub fn size(&self, file: impl Into<path::PathBuf>) -> io::Result<u64> {
+        Ok(fs::metadata(self.root.join(file.into()))?.len())
+    }
+
+    pub fn root(&self) -> &path::Path {
+        &self.root
+    }
 }
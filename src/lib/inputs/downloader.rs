@@ -26,9 +26,7 @@ impl Downloader {
     }
 
     pub fn get(&mut self, url: &str) -> io::Result<String> {
-        while self.last_download + self.timeout > time::Instant::now() {
-            thread::yield_now();
-        }
+        self.throttle();
 
         let text = self
             .client
@@ -43,4 +41,28 @@ impl Downloader {
 
         Ok(text)
     }
+
+    pub fn post(&mut self, url: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        self.throttle();
+
+        let text = self
+            .client
+            .post(url)
+            .header("Cookie", &self.cookie)
+            .form(form)
+            .send()
+            .map_err(into_io_error)?
+            .text()
+            .map_err(into_io_error)?;
+
+        self.last_download = time::Instant::now();
+
+        Ok(text)
+    }
+
+    fn throttle(&self) {
+        while self.last_download + self.timeout > time::Instant::now() {
+            thread::yield_now();
+        }
+    }
 }
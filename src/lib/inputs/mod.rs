@@ -1,57 +1,49 @@
 mod cache;
 mod downloader;
+mod submission;
 
-use std::io;
+use std::{
+    fs,
+    io::{self, Read},
+};
 
-use crate::{challenge::ChallengeObject, util::into_io_error};
+use crate::{challenge::ChallengeObject, util::challenge_key};
 
-use cache::FileCache;
+pub use cache::FileCache;
 use downloader::Downloader;
+pub use submission::*;
+
+const BASE_URL: &str = "https://adventofcode.com";
 
 pub struct AocInputs {
     cache: FileCache,
     downloader: Option<Downloader>,
+    base_url: String,
 }
 
-struct AocCredentials {
-    user_agent: String,
-    session_token: String,
-}
-
-impl AocCredentials {
-    fn read(config: &str) -> io::Result<Self> {
-        let config = config::Config::builder()
-            .add_source(config::File::new(config, config::FileFormat::Yaml))
-            .build()
-            .map_err(into_io_error)?;
-
-        Ok(Self {
-            user_agent: config.get("user_agent").map_err(into_io_error)?,
-            session_token: config.get("session_token").map_err(into_io_error)?,
-        })
-    }
+pub struct AocCredentials {
+    pub user_agent: String,
+    pub session_token: String,
 }
 
 impl AocInputs {
-    pub fn new(cache_root: String, downloader: Option<String>) -> io::Result<Self> {
-        let downloader = match downloader {
-            Some(config) => {
-                let creds = AocCredentials::read(&config)?;
-                Some(Downloader::new(
-                    creds.user_agent,
-                    format!("session={}", creds.session_token),
-                ))
-            }
-
-            None => None,
-        };
+    pub fn new(cache_root: String, credentials: Option<AocCredentials>) -> io::Result<Self> {
+        let downloader = credentials.map(|creds| {
+            Downloader::new(creds.user_agent, format!("session={}", creds.session_token))
+        });
 
         Ok(Self {
             cache: FileCache::open(cache_root)?,
             downloader,
+            base_url: BASE_URL.to_string(),
         })
     }
 
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
     pub fn get_inputs(&mut self, challenges: &mut [ChallengeObject]) -> io::Result<()> {
         for challenge in challenges {
             challenge.input = self.input(challenge)?;
@@ -60,22 +52,33 @@ impl AocInputs {
         Ok(())
     }
 
+    pub fn read_override(path: &str) -> io::Result<String> {
+        let input = match path {
+            "-" => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            path => fs::read_to_string(path)?,
+        };
+
+        Ok(input.trim_end().to_string())
+    }
+
     pub fn input(&mut self, challenge: &ChallengeObject) -> io::Result<String> {
-        let key = format!("{}_day{}", challenge.year, challenge.day);
+        self.fetch(challenge.year, challenge.day)
+    }
+
+    pub fn fetch(&mut self, year: usize, day: usize) -> io::Result<String> {
+        let key = challenge_key(year, day);
         if let Ok(input) = self.cache.get(&key) {
             return Ok(input);
         }
 
         if let Some(downloader) = self.downloader.as_mut() {
-            println!(
-                "Downloading input for {} day {}...",
-                challenge.year, challenge.day
-            );
+            eprintln!("Downloading input for {} day {}...", year, day);
 
-            let url = format!(
-                "https://adventofcode.com/{}/day/{}/input",
-                challenge.year, challenge.day
-            );
+            let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
 
             let input = downloader.get(&url)?;
 
@@ -86,7 +89,116 @@ impl AocInputs {
 
         Err(io::Error::new(
             io::ErrorKind::NotFound,
-            format!("Input for {} day {}", challenge.year, challenge.day),
+            format!("Input for {} day {}", year, day),
         ))
     }
+
+    pub fn submit(
+        &mut self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> io::Result<Submitted> {
+        let mut log = SubmissionLog::load(&self.cache)?;
+        if let Some(outcome) = log.known(year, day, part, answer) {
+            return Ok(Submitted::Known(outcome));
+        }
+
+        let Some(downloader) = self.downloader.as_mut() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Submitting answers requires credentials",
+            ));
+        };
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let response = downloader.post(&url, &[("level", &level), ("answer", answer)])?;
+
+        let outcome = SubmitOutcome::parse(&response);
+        log.record(year, day, part, answer, outcome.clone());
+        log.save(&self.cache)?;
+
+        Ok(Submitted::Sent(outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env,
+        io::{Read, Write},
+        net::TcpListener,
+        thread, time,
+    };
+
+    use super::*;
+
+    fn serve(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for body in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+
+                let mut request = vec![0; 4096];
+                let len = stream.read(&mut request).unwrap();
+                requests.push(String::from_utf8_lossy(&request[..len]).to_string());
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn submit_answers() {
+        let cache_root = env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache_root);
+
+        let (url, server) = serve(vec![
+            "<p>That's not the right answer; your answer is too high.</p>",
+            "<p>That's the right answer!</p>",
+        ]);
+
+        let mut inputs = AocInputs {
+            cache: FileCache::open(&cache_root).unwrap(),
+            downloader: Some(Downloader::with_timeout(
+                "test".to_string(),
+                "session=test".to_string(),
+                time::Duration::ZERO,
+            )),
+            base_url: String::new(),
+        }
+        .with_base_url(url);
+
+        let submit = |inputs: &mut AocInputs, answer| match inputs.submit(2024, 1, 1, answer) {
+            Ok(Submitted::Sent(outcome)) => (true, outcome),
+            Ok(Submitted::Known(outcome)) => (false, outcome),
+            Err(err) => panic!("{}", err),
+        };
+
+        assert_eq!(submit(&mut inputs, "100"), (true, SubmitOutcome::TooHigh));
+        assert_eq!(submit(&mut inputs, "100"), (false, SubmitOutcome::TooHigh));
+        assert_eq!(submit(&mut inputs, "200"), (false, SubmitOutcome::TooHigh));
+        assert_eq!(submit(&mut inputs, "42"), (true, SubmitOutcome::Correct));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/1/answer"));
+        assert!(requests[0].contains("level=1&answer=100"));
+        assert!(requests[1].contains("level=1&answer=42"));
+
+        std::fs::remove_dir_all(&cache_root).unwrap();
+    }
 }
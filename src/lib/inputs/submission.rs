@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, fmt, io};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    inputs::FileCache,
+    util::{challenge_key, into_io_error},
+};
+
+const SUBMISSIONS_FILE: &str = "submissions.yaml";
+
+lazy_static! {
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(u64),
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmitOutcome {
+    pub fn parse(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            return SubmitOutcome::Correct;
+        }
+
+        if html.contains("That's not the right answer") {
+            return if html.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if html.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Incorrect
+            };
+        }
+
+        if html.contains("You gave an answer too recently") {
+            let seconds = WAIT.captures(html).map_or(0, |caps| {
+                let minutes = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds = caps[2].parse::<u64>().unwrap();
+                minutes * 60 + seconds
+            });
+
+            return SubmitOutcome::Wait(seconds);
+        }
+
+        if html.contains("You don't seem to be solving the right level") {
+            return SubmitOutcome::AlreadySolved;
+        }
+
+        SubmitOutcome::Unknown
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::Correct
+                | SubmitOutcome::TooHigh
+                | SubmitOutcome::TooLow
+                | SubmitOutcome::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "Correct"),
+            SubmitOutcome::TooHigh => write!(f, "Too high"),
+            SubmitOutcome::TooLow => write!(f, "Too low"),
+            SubmitOutcome::Incorrect => write!(f, "Incorrect"),
+            SubmitOutcome::Wait(seconds) => write!(f, "Wait {} seconds", seconds),
+            SubmitOutcome::AlreadySolved => write!(f, "Already solved"),
+            SubmitOutcome::Unknown => write!(f, "Unknown response"),
+        }
+    }
+}
+
+pub enum Submitted {
+    Sent(SubmitOutcome),
+    Known(SubmitOutcome),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+}
+
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    submissions: BTreeMap<String, Vec<Submission>>,
+}
+
+impl SubmissionLog {
+    pub fn load(cache: &FileCache) -> io::Result<Self> {
+        let submissions = match cache.get(SUBMISSIONS_FILE) {
+            Ok(contents) => serde_yaml::from_str(&contents).map_err(into_io_error)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self { submissions })
+    }
+
+    pub fn save(&self, cache: &FileCache) -> io::Result<()> {
+        let contents = serde_yaml::to_string(&self.submissions).map_err(into_io_error)?;
+        cache.set(SUBMISSIONS_FILE, contents)
+    }
+
+    pub fn known(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Option<SubmitOutcome> {
+        let submissions = self.submissions.get(&Self::key(year, day, part))?;
+        let value = answer.parse::<i128>().ok();
+
+        submissions
+            .iter()
+            .filter(|submission| submission.outcome.is_final())
+            .find_map(|submission| {
+                if submission.answer == answer {
+                    return Some(submission.outcome.clone());
+                }
+
+                let bound = submission.answer.parse::<i128>().ok();
+                match (&submission.outcome, value, bound) {
+                    (SubmitOutcome::Correct, _, _) => Some(SubmitOutcome::Incorrect),
+                    (SubmitOutcome::TooHigh, Some(value), Some(bound)) if value > bound => {
+                        Some(SubmitOutcome::TooHigh)
+                    }
+                    (SubmitOutcome::TooLow, Some(value), Some(bound)) if value < bound => {
+                        Some(SubmitOutcome::TooLow)
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn record(
+        &mut self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+        outcome: SubmitOutcome,
+    ) {
+        self.submissions
+            .entry(Self::key(year, day, part))
+            .or_default()
+            .push(Submission {
+                answer: answer.to_string(),
+                outcome,
+            });
+    }
+
+    fn key(year: usize, day: usize, part: usize) -> String {
+        format!("{}_part{}", challenge_key(year, day), part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_outcomes() {
+        let parse = SubmitOutcome::parse;
+
+        assert_eq!(
+            parse("<p>That's the right answer! You are one gold star closer.</p>"),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            parse("<p>That's not the right answer; your answer is too high.</p>"),
+            SubmitOutcome::TooHigh
+        );
+        assert_eq!(
+            parse("<p>That's not the right answer; your answer is too low.</p>"),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            parse("<p>That's not the right answer.</p>"),
+            SubmitOutcome::Incorrect
+        );
+        assert_eq!(
+            parse("<p>You gave an answer too recently. You have 1m 23s left to wait.</p>"),
+            SubmitOutcome::Wait(83)
+        );
+        assert_eq!(
+            parse("<p>You gave an answer too recently. You have 45s left to wait.</p>"),
+            SubmitOutcome::Wait(45)
+        );
+        assert_eq!(
+            parse("<p>You don't seem to be solving the right level.</p>"),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(parse("<html></html>"), SubmitOutcome::Unknown);
+    }
+
+    #[test]
+    fn known_submissions() {
+        let mut log = SubmissionLog::default();
+
+        log.record(2024, 1, 1, "100", SubmitOutcome::TooHigh);
+        log.record(2024, 1, 1, "10", SubmitOutcome::TooLow);
+        log.record(2024, 1, 1, "50", SubmitOutcome::Wait(30));
+
+        assert_eq!(log.known(2024, 1, 1, "100"), Some(SubmitOutcome::TooHigh));
+        assert_eq!(log.known(2024, 1, 1, "150"), Some(SubmitOutcome::TooHigh));
+        assert_eq!(log.known(2024, 1, 1, "5"), Some(SubmitOutcome::TooLow));
+        assert_eq!(log.known(2024, 1, 1, "50"), None);
+        assert_eq!(log.known(2024, 1, 2, "100"), None);
+
+        log.record(2024, 1, 1, "42", SubmitOutcome::Correct);
+
+        assert_eq!(log.known(2024, 1, 1, "42"), Some(SubmitOutcome::Correct));
+        assert_eq!(log.known(2024, 1, 1, "43"), Some(SubmitOutcome::Incorrect));
+    }
+}
@@ -1,10 +1,24 @@
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod calendar;
 pub mod challenge;
 pub mod executor;
 pub mod helpers;
+pub mod history;
 pub mod inputs;
+pub mod memory;
+pub mod progress;
+pub mod report;
+pub mod scaffold;
+pub mod selection;
+pub mod settings;
 pub mod util;
 
+#[doc(hidden)]
+pub use inventory;
+
 pub mod prelude {
-    pub use crate::challenge::{Challenge, ThreadedChallenge};
-    pub use crate::{aoc, day, example, year};
+    pub use crate::challenge::{Challenge, StagedChallenge, ThreadedChallenge};
+    pub use crate::{aoc, cost, day, example, timeout, year};
 }
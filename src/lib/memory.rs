@@ -0,0 +1,242 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ptr,
+    sync::{
+        Arc,
+        atomic::{AtomicI64, AtomicU64, Ordering},
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicI64,
+    peak: AtomicI64,
+}
+
+impl Counters {
+    fn alloc(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size as u64, Ordering::Relaxed);
+
+        let live = self.live.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn adopt(&self, size: usize) {
+        let live = self.live.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn dealloc(&self, size: usize) {
+        self.live.fetch_sub(size as i64, Ordering::Relaxed);
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<*const Counters> = const { Cell::new(ptr::null()) };
+}
+
+fn with_current(f: impl FnOnce(&Counters)) {
+    let current = CURRENT.try_with(Cell::get).unwrap_or(ptr::null());
+
+    // SAFETY: the pointer is only set while a `Scope` keeps its counters alive
+    if let Some(counters) = unsafe { current.as_ref() } {
+        f(counters);
+    }
+}
+
+pub const fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        with_current(|counters| counters.alloc(layout.size()));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        with_current(|counters| counters.alloc(layout.size()));
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        with_current(|counters| counters.dealloc(layout.size()));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        with_current(|counters| {
+            counters.dealloc(layout.size());
+            counters.alloc(new_size);
+        });
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Tracker(Option<Arc<Counters>>);
+
+impl Tracker {
+    pub fn new() -> Self {
+        Self(enabled().then(Default::default))
+    }
+
+    pub fn current() -> Self {
+        if !enabled() {
+            return Self(None);
+        }
+
+        let current = CURRENT.with(Cell::get);
+        if current.is_null() {
+            return Self(None);
+        }
+
+        // SAFETY: the pointer came from `Arc::as_ptr` and the entered scope still holds the Arc
+        unsafe {
+            Arc::increment_strong_count(current);
+            Self(Some(Arc::from_raw(current)))
+        }
+    }
+
+    // memory allocated before the scope but freed inside it, like the input, has to count as
+    // live, otherwise freeing it pushes the live bytes below zero
+    pub fn adopt(&self, bytes: usize) {
+        if let Some(counters) = self.0.as_ref() {
+            counters.adopt(bytes);
+        }
+    }
+
+    pub fn enter(&self) -> Scope<'_> {
+        let previous = self
+            .0
+            .as_ref()
+            .map(|counters| CURRENT.with(|current| current.replace(Arc::as_ptr(counters))));
+
+        Scope {
+            _tracker: self,
+            previous,
+        }
+    }
+
+    pub fn stats(&self) -> Option<AllocStats> {
+        self.0.as_ref().map(|counters| AllocStats {
+            allocations: counters.allocations.load(Ordering::Relaxed),
+            bytes: counters.bytes.load(Ordering::Relaxed),
+            peak: counters.peak.load(Ordering::Relaxed).max(0) as u64,
+        })
+    }
+}
+
+pub struct Scope<'a> {
+    _tracker: &'a Tracker,
+    previous: Option<*const Counters>,
+}
+
+impl Drop for Scope<'_> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous {
+            CURRENT.with(|current| current.set(previous));
+        }
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.2} {}", value, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc-stats")]
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn counts_allocations() {
+        use std::{hint::black_box, thread};
+
+        let tracker = Tracker::new();
+
+        {
+            let _scope = tracker.enter();
+            let first = black_box(vec![0u8; 1000]);
+            drop(first);
+            let second = black_box(vec![0u8; 600]);
+
+            let nested = Tracker::current();
+            thread::spawn(move || {
+                let _scope = nested.enter();
+                black_box(vec![0u8; 400]);
+            })
+            .join()
+            .unwrap();
+
+            drop(second);
+        }
+
+        let stats = tracker.stats().unwrap();
+        assert!(stats.allocations >= 3);
+        assert!(stats.bytes >= 2000);
+        assert!((1000..2000).contains(&stats.peak));
+
+        black_box(vec![0u8; 5000]);
+        assert_eq!(tracker.stats(), Some(stats));
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn adopted_input() {
+        use std::hint::black_box;
+
+        let input = black_box(vec![0u8; 1000]);
+
+        let tracker = Tracker::new();
+        tracker.adopt(input.capacity());
+
+        {
+            let _scope = tracker.enter();
+            drop(input);
+            black_box(vec![0u8; 600]);
+            black_box(vec![0u8; 800]);
+        }
+
+        let stats = tracker.stats().unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1400);
+        assert_eq!(stats.peak, 1000);
+    }
+}
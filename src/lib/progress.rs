@@ -0,0 +1,217 @@
+use std::time::{Duration, Instant};
+
+use console::{Term, style};
+
+use crate::{challenge::Status, executor::Event};
+
+const REDRAW: Duration = Duration::from_millis(100);
+
+struct Running {
+    year: usize,
+    day: usize,
+    workers: Vec<usize>,
+    start: Instant,
+}
+
+pub struct Progress {
+    term: Term,
+    live: bool,
+    queued: Vec<(usize, usize)>,
+    running: Vec<Running>,
+    finished: usize,
+    drawn: usize,
+    last_draw: Option<Instant>,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        let term = Term::stdout();
+        let live = term.is_term();
+        Self::with_term(term, live)
+    }
+
+    pub fn with_term(term: Term, live: bool) -> Self {
+        Self {
+            term,
+            live,
+            queued: Vec::new(),
+            running: Vec::new(),
+            finished: 0,
+            drawn: 0,
+            last_draw: None,
+        }
+    }
+
+    pub fn handle(&mut self, event: Event<'_>) {
+        if !self.live
+            && let Some(line) = plain_line(&event)
+        {
+            let _ = self.term.write_line(&line);
+        }
+
+        match event {
+            Event::Queued { year, day } => self.queued.push((year, day)),
+
+            Event::Started { year, day, workers } => {
+                self.queued.retain(|queued| *queued != (year, day));
+                self.running.push(Running {
+                    year,
+                    day,
+                    workers: workers.to_vec(),
+                    start: Instant::now(),
+                });
+            }
+
+            Event::Finished(result) => {
+                self.running
+                    .retain(|running| (running.year, running.day) != (result.year, result.day));
+                self.finished += 1;
+            }
+
+            Event::Tick => {
+                if self.last_draw.is_some_and(|last| last.elapsed() < REDRAW) {
+                    return;
+                }
+            }
+
+            Event::Done => {
+                self.clear();
+                return;
+            }
+        }
+
+        self.draw();
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let total = self.queued.len() + self.running.len() + self.finished;
+
+        let summary = format!(
+            "Finished {}/{}, running {}, queued {}",
+            self.finished,
+            total,
+            self.running.len(),
+            self.queued.len()
+        );
+
+        let mut lines = vec![format!(" {}", style(summary).bold())];
+        for running in self.running.iter() {
+            let workers = running
+                .workers
+                .iter()
+                .map(|id| format!("w{}", id))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            lines.push(format!(
+                "   {} day {:>2}  {:>10}  [{}]",
+                style(running.year).blue(),
+                style(running.day).green(),
+                style(format!("{:.2?}", running.start.elapsed())).yellow(),
+                workers
+            ));
+        }
+
+        lines
+    }
+
+    fn draw(&mut self) {
+        if !self.live {
+            return;
+        }
+
+        let lines = self.lines();
+
+        self.clear();
+        for line in lines.iter() {
+            let _ = self.term.write_line(line);
+        }
+
+        self.drawn = lines.len();
+        self.last_draw = Some(Instant::now());
+    }
+
+    fn clear(&mut self) {
+        if self.live && self.drawn > 0 {
+            let _ = self.term.clear_last_lines(self.drawn);
+        }
+
+        self.drawn = 0;
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn plain_line(event: &Event<'_>) -> Option<String> {
+    match event {
+        Event::Started { year, day, workers } => Some(format!(
+            " started  {} day {} on workers {:?}",
+            year, day, workers
+        )),
+
+        Event::Finished(result) => Some(match result.status {
+            Status::Solved => format!(
+                " finished {} day {} in {:.2?}",
+                result.year, result.day, result.duration
+            ),
+            ref status => format!(" finished {} day {} {}", result.year, result.day, status),
+        }),
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::challenge::ChallengeResult;
+
+    #[test]
+    fn tracks_challenges() {
+        let mut progress = Progress::with_term(Term::buffered_stdout(), false);
+
+        progress.handle(Event::Queued { year: 2024, day: 1 });
+        progress.handle(Event::Queued { year: 2024, day: 2 });
+        progress.handle(Event::Started {
+            year: 2024,
+            day: 1,
+            workers: &[3, 4],
+        });
+
+        let lines = progress.lines();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("Finished 0/2, running 1, queued 1"));
+        assert!(lines[1].contains("[w3 w4]"));
+
+        let result = ChallengeResult::timed_out(2024, 1, Duration::from_secs(1), 2);
+        progress.handle(Event::Finished(&result));
+
+        let lines = progress.lines();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("Finished 1/2, running 0, queued 1"));
+    }
+
+    #[test]
+    fn plain_lines() {
+        let event = Event::Started {
+            year: 2024,
+            day: 5,
+            workers: &[1, 2],
+        };
+        assert_eq!(
+            plain_line(&event).unwrap(),
+            " started  2024 day 5 on workers [1, 2]"
+        );
+
+        let result = ChallengeResult::timed_out(2024, 5, Duration::from_secs(1), 2);
+        assert_eq!(
+            plain_line(&Event::Finished(&result)).unwrap(),
+            " finished 2024 day 5 TIMEOUT"
+        );
+        assert!(plain_line(&Event::Tick).is_none());
+    }
+}
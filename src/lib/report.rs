@@ -0,0 +1,384 @@
+use std::{io, time::Duration};
+
+use serde::Serialize;
+
+use crate::{
+    answers::Verdict,
+    bench,
+    challenge::{ChallengeResult, Stages, Status},
+    memory::AllocStats,
+    util::into_io_error,
+};
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: usize,
+    day: usize,
+    part1: &'a str,
+    part2: &'a str,
+    duration_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_ns: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1_ns: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2_ns: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<Option<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<Option<u64>>,
+    workers: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verify: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example_part1: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example_part2: Option<&'a str>,
+}
+
+fn records<'a>(
+    results: &'a [ChallengeResult],
+    verdicts: Option<&[(Verdict, Verdict)]>,
+) -> impl Iterator<Item = Record<'a>> {
+    let examples = results.iter().any(|result| result.example.is_some());
+    let example = move |result: &'a ChallengeResult, part: fn(&'a (String, String)) -> &'a str| {
+        examples.then(|| result.example.as_ref().map_or("", |e| part(&e.solution)))
+    };
+
+    let unsolved = results.iter().any(|result| !result.solved());
+
+    let staged = results.iter().any(|result| result.stages.is_some());
+    let stage = move |result: &ChallengeResult, stage: fn(&Stages) -> Duration| {
+        staged.then(|| result.stages.as_ref().map(|s| stage(s).as_nanos() as u64))
+    };
+
+    let measured = results.iter().any(|result| result.memory.is_some());
+    let memory = move |result: &ChallengeResult, stat: fn(&AllocStats) -> u64| {
+        measured.then(|| result.memory.as_ref().map(stat))
+    };
+
+    results.iter().enumerate().map(move |(i, result)| Record {
+        year: result.year,
+        day: result.day,
+        part1: &result.solution.0,
+        part2: &result.solution.1,
+        duration_ns: result.duration.as_nanos() as u64,
+        status: unsolved.then(|| result.status.to_string()),
+        error: unsolved.then_some(match &result.status {
+            Status::Failed(message) => message.as_str(),
+            _ => "",
+        }),
+        parse_ns: stage(result, |s| s.parse),
+        part1_ns: stage(result, |s| s.part1),
+        part2_ns: stage(result, |s| s.part2),
+        allocations: memory(result, |m| m.allocations),
+        allocated_bytes: memory(result, |m| m.bytes),
+        peak_bytes: memory(result, |m| m.peak),
+        workers: result.workers,
+        verify: verdicts.map(|verdicts| verdicts[i].0.combine(verdicts[i].1).to_string()),
+        example_part1: example(result, |s| &s.0),
+        example_part2: example(result, |s| &s.1),
+    })
+}
+
+pub fn json(
+    results: &[ChallengeResult],
+    verdicts: Option<&[(Verdict, Verdict)]>,
+) -> io::Result<String> {
+    let records = records(results, verdicts).collect::<Vec<_>>();
+    serde_json::to_string_pretty(&records).map_err(into_io_error)
+}
+
+pub fn csv(
+    results: &[ChallengeResult],
+    verdicts: Option<&[(Verdict, Verdict)]>,
+) -> io::Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for record in records(results, verdicts) {
+        writer.serialize(record).map_err(into_io_error)?;
+    }
+
+    let bytes = writer.into_inner().map_err(into_io_error)?;
+    String::from_utf8(bytes).map_err(into_io_error)
+}
+
+pub fn markdown(results: &[ChallengeResult], verdicts: Option<&[(Verdict, Verdict)]>) -> String {
+    let escape = |s: &str| s.replace('|', "\\|").replace('\n', "<br>");
+
+    let mut out = String::from("| Year | Day | Part 1 | Part 2 | Time | Workers |");
+    let mut rule = String::from("\n| ---: | --: | :----- | :----- | ---: | ------: |");
+    if verdicts.is_some() {
+        out.push_str(" Verify |");
+        rule.push_str(" :----- |");
+    }
+
+    out.push_str(&rule);
+
+    for (record, result) in records(results, verdicts).zip(results) {
+        out.push_str(&format!(
+            "\n| {} | {} | {} | {} | {} | {} |",
+            record.year,
+            record.day,
+            escape(record.part1),
+            escape(record.part2),
+            match result.status {
+                Status::Solved => format!("{:.2?}", result.duration),
+                ref status => status.to_string(),
+            },
+            record.workers,
+        ));
+
+        if let Some(verify) = record.verify {
+            out.push_str(&format!(" {} |", verify));
+        }
+    }
+
+    out.push('\n');
+    out
+}
+
+pub struct YearSummary {
+    pub year: usize,
+    pub days: usize,
+    pub failed: usize,
+    pub total: Duration,
+    pub median: Option<Duration>,
+    pub slowest: Option<(usize, Duration)>,
+}
+
+pub fn summarize(results: &[ChallengeResult]) -> Vec<YearSummary> {
+    let mut years = results.iter().map(|r| r.year).collect::<Vec<_>>();
+    years.sort();
+    years.dedup();
+
+    years
+        .into_iter()
+        .map(|year| {
+            let (solved, failed) = results
+                .iter()
+                .filter(|r| r.year == year)
+                .partition::<Vec<_>, _>(|r| r.solved());
+
+            // a timeout or a panic says nothing about how fast the day is
+            let mut days = solved
+                .iter()
+                .map(|r| (r.duration, r.day))
+                .collect::<Vec<_>>();
+            days.sort();
+            let durations = days
+                .iter()
+                .map(|(duration, _)| *duration)
+                .collect::<Vec<_>>();
+
+            YearSummary {
+                year,
+                days: days.len(),
+                failed: failed.len(),
+                total: durations.iter().sum(),
+                median: bench::median(&durations),
+                slowest: days.last().map(|(duration, day)| (*day, *duration)),
+            }
+        })
+        .collect()
+}
+
+pub struct Parallelism {
+    pub sum: Duration,
+    pub speedup: f64,
+    pub efficiency: f64,
+}
+
+pub fn parallelism(results: &[ChallengeResult], wall: Duration, workers: usize) -> Parallelism {
+    let sum = results.iter().map(|r| r.duration).sum::<Duration>();
+    let busy = results
+        .iter()
+        .map(|r| r.duration * r.workers as u32)
+        .sum::<Duration>();
+
+    let wall = wall.as_secs_f64().max(f64::EPSILON);
+    Parallelism {
+        sum,
+        speedup: sum.as_secs_f64() / wall,
+        efficiency: busy.as_secs_f64() / (wall * workers.max(1) as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<ChallengeResult> {
+        vec![
+            ChallengeResult::new(
+                2024,
+                1,
+                ("10".to_string(), "a|b".to_string()),
+                Duration::from_micros(15),
+                1,
+            ),
+            ChallengeResult::new(
+                2024,
+                2,
+                ("x\ny".to_string(), "".to_string()),
+                Duration::from_millis(2),
+                4,
+            ),
+        ]
+    }
+
+    #[test]
+    fn staged_records() {
+        let mut results = results();
+        results[1].stages = Some(Stages {
+            parse: Duration::from_nanos(10),
+            part1: Duration::from_nanos(20),
+            part2: Duration::from_nanos(30),
+        });
+
+        let out = csv(&results, None).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines[0],
+            "year,day,part1,part2,duration_ns,parse_ns,part1_ns,part2_ns,workers"
+        );
+        assert_eq!(lines[1], "2024,1,10,a|b,15000,,,,1");
+    }
+
+    #[test]
+    fn unsolved_records() {
+        let mut results = results();
+        results[1] = ChallengeResult::timed_out(2024, 2, Duration::from_secs(1), 4);
+
+        let value: serde_json::Value =
+            serde_json::from_str(&json(&results, None).unwrap()).unwrap();
+
+        assert_eq!(value[0]["status"], "OK");
+        assert_eq!(value[1]["status"], "TIMEOUT");
+        assert_eq!(value[1]["part1"], "");
+        assert_eq!(value[1]["error"], "");
+
+        results[0] = ChallengeResult::failed(2024, 1, "boom".into(), Duration::ZERO, 1);
+
+        let value: serde_json::Value =
+            serde_json::from_str(&json(&results, None).unwrap()).unwrap();
+
+        assert_eq!(value[0]["status"], "FAILED");
+        assert_eq!(value[0]["error"], "boom");
+
+        let out = markdown(&results, None);
+        assert_eq!(out.lines().nth(3), Some("| 2024 | 2 |  |  | TIMEOUT | 4 |"));
+    }
+
+    #[test]
+    fn memory_records() {
+        let mut results = results();
+        results[0].memory = Some(AllocStats {
+            allocations: 3,
+            bytes: 300,
+            peak: 200,
+        });
+
+        let value: serde_json::Value =
+            serde_json::from_str(&json(&results, None).unwrap()).unwrap();
+
+        assert_eq!(value[0]["allocations"], 3);
+        assert_eq!(value[0]["peak_bytes"], 200);
+        assert!(value[1]["allocated_bytes"].is_null());
+    }
+
+    #[test]
+    fn year_summaries() {
+        let summaries = summarize(&results());
+        assert_eq!(summaries[0].median, Some(Duration::from_nanos(1_007_500)));
+
+        let mut results = results();
+        results[0].year = 2023;
+        results.push(ChallengeResult::timed_out(
+            2024,
+            3,
+            Duration::from_secs(1),
+            1,
+        ));
+        results.push(ChallengeResult::timed_out(
+            2024,
+            4,
+            Duration::from_millis(1),
+            1,
+        ));
+
+        let summaries = summarize(&results);
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].year, 2023);
+        assert_eq!(summaries[0].total, Duration::from_micros(15));
+        assert_eq!(summaries[0].failed, 0);
+        assert_eq!(summaries[1].days, 1);
+        assert_eq!(summaries[1].failed, 2);
+        assert_eq!(summaries[1].median, Some(Duration::from_millis(2)));
+        assert_eq!(summaries[1].slowest, Some((2, Duration::from_millis(2))));
+        assert_eq!(summaries[1].total, Duration::from_millis(2));
+
+        let summaries = summarize(&results[2..]);
+        assert_eq!(summaries[0].days, 0);
+        assert_eq!(summaries[0].failed, 2);
+        assert_eq!(summaries[0].median, None);
+        assert_eq!(summaries[0].slowest, None);
+    }
+
+    #[test]
+    fn parallel_efficiency() {
+        let mut results = results();
+        results[0].duration = Duration::from_millis(6);
+
+        let parallelism = parallelism(&results, Duration::from_millis(7), 4);
+
+        assert_eq!(parallelism.sum, Duration::from_millis(8));
+        assert!((parallelism.speedup - 8.0 / 7.0).abs() < 1e-9);
+        assert!((parallelism.efficiency - 14.0 / 28.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn json_records() {
+        let value: serde_json::Value =
+            serde_json::from_str(&json(&results(), None).unwrap()).unwrap();
+
+        assert_eq!(value[0]["year"], 2024);
+        assert_eq!(value[0]["part2"], "a|b");
+        assert_eq!(value[1]["duration_ns"], 2_000_000);
+        assert_eq!(value[1]["workers"], 4);
+        assert!(value[1].get("verify").is_none());
+    }
+
+    #[test]
+    fn csv_records() {
+        let verdicts = [
+            (Verdict::Pass, Verdict::Pass),
+            (Verdict::Pass, Verdict::Fail),
+        ];
+        let out = csv(&results(), Some(&verdicts)).unwrap();
+
+        assert_eq!(
+            out,
+            "year,day,part1,part2,duration_ns,workers,verify\n\
+             2024,1,10,a|b,15000,1,Pass\n\
+             2024,2,\"x\ny\",,2000000,4,Fail\n"
+        );
+    }
+
+    #[test]
+    fn markdown_table() {
+        let out = markdown(&results(), None);
+        let lines = out.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2], "| 2024 | 1 | 10 | a\\|b | 15.00µs | 1 |");
+        assert_eq!(lines[3], "| 2024 | 2 | x<br>y |  | 2.00ms | 4 |");
+    }
+}
@@ -0,0 +1,67 @@
+pub fn day_template(year: usize, day: usize, threaded: bool) -> String {
+    if threaded {
+        format!(
+            r#"use lib::{{aoc, challenge::ThreadedChallenge, executor::WorkerGroup, workers}};
+
+pub struct Day{day};
+
+impl ThreadedChallenge for Day{day} {{
+    aoc!(Day{day}, year = {year}, day = {day});
+
+    workers!(4);
+    fn solve(input: String, workers: &mut WorkerGroup) -> (String, String) {{
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let fst = workers
+            .par_map(lines.as_slice(), |line| line.len())
+            .into_iter()
+            .sum::<usize>();
+
+        (fst.to_string(), "".to_string())
+    }}
+}}
+"#
+        )
+    } else {
+        format!(
+            r#"use lib::aoc;
+use lib::challenge::Challenge;
+
+pub struct Day{day};
+
+impl Challenge for Day{day} {{
+    aoc!(Day{day}, year = {year}, day = {day});
+
+    fn solve(input: String) -> (String, String) {{
+        let fst = input.lines().count();
+
+        (fst.to_string(), "".to_string())
+    }}
+}}
+"#
+        )
+    }
+}
+
+pub fn year_template(year: usize) -> String {
+    format!("automod::dir!(pub \"src/aoc{}\");\n", year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates() {
+        assert_eq!(year_template(2030), "automod::dir!(pub \"src/aoc2030\");\n");
+
+        let day = day_template(2030, 7, false);
+        assert!(day.contains("impl Challenge for Day7 {"));
+        assert!(day.contains("aoc!(Day7, year = 2030, day = 7);"));
+
+        let day = day_template(2030, 7, true);
+        assert!(day.contains("impl ThreadedChallenge for Day7 {"));
+        assert!(day.contains("workers!(4);"));
+        assert!(day.contains("workers\n            .par_map(lines.as_slice(), |line| line.len())"));
+    }
+}
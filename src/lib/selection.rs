@@ -0,0 +1,130 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    years: Option<RangeInclusive<usize>>,
+    days: Option<RangeInclusive<usize>>,
+}
+
+impl Rule {
+    fn matches(&self, year: usize, day: usize) -> bool {
+        self.years
+            .as_ref()
+            .is_none_or(|years| years.contains(&year))
+            && self.days.as_ref().is_none_or(|days| days.contains(&day))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection {
+    rules: Vec<Rule>,
+}
+
+impl Selection {
+    pub fn matches(&self, year: usize, day: usize) -> bool {
+        self.rules.iter().any(|rule| rule.matches(year, day))
+    }
+}
+
+fn parse_range(s: &str) -> Result<Option<RangeInclusive<usize>>, String> {
+    let number = |s: &str| {
+        s.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("invalid number '{}'", s))
+    };
+
+    match s.trim() {
+        "*" => Ok(None),
+        s => match s.split_once('-') {
+            Some((lo, hi)) => {
+                let (lo, hi) = (number(lo)?, number(hi)?);
+                if lo > hi {
+                    return Err(format!("empty range '{}'", s));
+                }
+
+                Ok(Some(lo..=hi))
+            }
+            None => number(s).map(|n| Some(n..=n)),
+        },
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules: Vec<Rule> = Vec::new();
+
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let rule = match token.split_once(':') {
+                Some((years, days)) => Rule {
+                    years: parse_range(years)?,
+                    days: parse_range(days)?,
+                },
+
+                // a bare day list continues the previous year, e.g. `2022:1,3,5-7`
+                None => match (rules.last(), parse_range(token)?) {
+                    (Some(prev), Some(days)) if *days.end() <= 25 => Rule {
+                        years: prev.years.clone(),
+                        days: Some(days),
+                    },
+                    (_, years) => Rule { years, days: None },
+                },
+            };
+
+            rules.push(rule);
+        }
+
+        if rules.is_empty() {
+            return Err("empty selection".to_string());
+        }
+
+        Ok(Self { rules })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_selection() {
+        let selection: Selection = "2022:1-10,2023:*,2024:17".parse().unwrap();
+
+        assert!(selection.matches(2022, 1));
+        assert!(selection.matches(2022, 10));
+        assert!(!selection.matches(2022, 11));
+        assert!(selection.matches(2023, 25));
+        assert!(selection.matches(2024, 17));
+        assert!(!selection.matches(2024, 16));
+        assert!(!selection.matches(2025, 1));
+    }
+
+    #[test]
+    fn parse_shorthands() {
+        let selection: Selection = "2022:1,3,5-7,2023".parse().unwrap();
+
+        assert!(selection.matches(2022, 3));
+        assert!(selection.matches(2022, 6));
+        assert!(!selection.matches(2022, 4));
+        assert!(selection.matches(2023, 12));
+
+        let selection: Selection = "*:25".parse().unwrap();
+
+        assert!(selection.matches(2022, 25));
+        assert!(selection.matches(2024, 25));
+        assert!(!selection.matches(2024, 24));
+
+        let selection: Selection = "2022-2023:1".parse().unwrap();
+
+        assert!(selection.matches(2023, 1));
+        assert!(!selection.matches(2024, 1));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("".parse::<Selection>().is_err());
+        assert!("2022:x".parse::<Selection>().is_err());
+        assert!("2022:10-1".parse::<Selection>().is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    env, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use config::{Config, Environment, File, FileFormat, Source};
+
+use crate::{inputs::AocCredentials, util::into_io_error};
+
+pub const KEYS: &[&str] = &[
+    "year",
+    "worker_threads",
+    "inputs_cache",
+    "base_url",
+    "format",
+    "timeout",
+    "user_agent",
+    "session_token",
+];
+
+const ENV_PREFIX: &str = "AOC";
+const PROJECT_FILE: &str = "aoc.yaml";
+
+pub fn config_files() -> Vec<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    config_home
+        .map(|dir| dir.join("aoc").join("config.yaml"))
+        .into_iter()
+        .chain(Some(PathBuf::from(PROJECT_FILE)))
+        .collect()
+}
+
+#[derive(Debug, Default)]
+pub struct Settings {
+    config: Config,
+}
+
+impl Settings {
+    pub fn load(
+        files: &[PathBuf],
+        env: Option<HashMap<String, String>>,
+        credentials: Option<&Path>,
+        overrides: &[(&str, Option<String>)],
+    ) -> io::Result<Self> {
+        let mut builder = Config::builder();
+
+        for file in files {
+            builder = builder.add_source(
+                File::from(file.as_path())
+                    .format(FileFormat::Yaml)
+                    .required(false),
+            );
+        }
+
+        if let Some(credentials) = credentials {
+            builder = builder.add_source(File::from(credentials).format(FileFormat::Yaml));
+        }
+
+        // other tools share the AOC_ prefix, so unknown variables are skipped instead of rejected
+        let prefix = format!("{}_", ENV_PREFIX);
+        let vars = env
+            .unwrap_or_else(|| env::vars().collect())
+            .into_iter()
+            .filter(|(key, _)| {
+                key.strip_prefix(&prefix)
+                    .is_some_and(|key| KEYS.contains(&key.to_lowercase().as_str()))
+            })
+            .collect();
+
+        builder = builder.add_source(
+            Environment::with_prefix(ENV_PREFIX)
+                .prefix_separator("_")
+                .source(Some(vars)),
+        );
+
+        for (key, value) in overrides {
+            builder = builder
+                .set_override_option(*key, value.clone())
+                .map_err(into_io_error)?;
+        }
+
+        let config = builder.build().map_err(into_io_error)?;
+
+        let collected = config.collect().map_err(into_io_error)?;
+        if let Some(key) = collected.keys().find(|key| !KEYS.contains(&key.as_str())) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unknown setting '{}'", key),
+            ));
+        }
+
+        Ok(Self { config })
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.config.get_string(key).ok()
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> io::Result<Option<T>> {
+        self.get_string(key)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Invalid value '{}' for setting '{}'", value, key),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    pub fn source(&self, key: &str) -> Option<String> {
+        let collected = self.config.collect().ok()?;
+        let value = collected.get(key)?;
+
+        Some(value.origin().unwrap_or("command line").to_string())
+    }
+
+    pub fn credentials(&self) -> Option<AocCredentials> {
+        Some(AocCredentials {
+            user_agent: self.get_string("user_agent")?,
+            session_token: self.get_string("session_token")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn layered_settings() {
+        let dir = env::temp_dir().join(format!("aoc_settings_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let user = dir.join("config.yaml");
+        let project = dir.join("aoc.yaml");
+        fs::write(&user, "worker_threads: 4\nyear: 2022\nuser_agent: me\n").unwrap();
+        fs::write(&project, "year: 2023\nsession_token: abc\n").unwrap();
+
+        let env = HashMap::from([
+            ("AOC_YEAR".to_string(), "2024".to_string()),
+            ("AOC_SESSION".to_string(), "unrelated".to_string()),
+        ]);
+        let overrides = [("worker_threads", Some("8".to_string())), ("timeout", None)];
+
+        let credentials = dir.join("credentials.yaml");
+        fs::write(&credentials, "user_agent: them\n").unwrap();
+
+        let settings = Settings::load(
+            &[user.clone(), project.clone()],
+            Some(env),
+            Some(&credentials),
+            &overrides,
+        )
+        .unwrap();
+
+        assert_eq!(settings.get::<usize>("worker_threads").unwrap(), Some(8));
+        assert_eq!(settings.source("worker_threads").unwrap(), "command line");
+        assert_eq!(settings.get::<usize>("year").unwrap(), Some(2024));
+        assert_eq!(settings.source("year").unwrap(), "the environment");
+        assert_eq!(settings.get::<f64>("timeout").unwrap(), None);
+
+        let credentials = settings.credentials().unwrap();
+        assert!(
+            settings
+                .source("user_agent")
+                .unwrap()
+                .ends_with("credentials.yaml")
+        );
+        assert_eq!(credentials.user_agent, "them");
+        assert_eq!(credentials.session_token, "abc");
+        assert!(
+            settings
+                .source("session_token")
+                .unwrap()
+                .ends_with("aoc.yaml")
+        );
+
+        let env = HashMap::from([("AOC_USER_AGENT".to_string(), "env".to_string())]);
+        let credentials = dir.join("credentials.yaml");
+        let settings = Settings::load(&[], Some(env), Some(&credentials), &[]).unwrap();
+        assert_eq!(settings.get_string("user_agent").unwrap(), "env");
+
+        fs::write(&project, "colour: red\n").unwrap();
+        assert!(Settings::load(&[project], Some(HashMap::new()), None, &[]).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,9 @@
 use std::io;
 
 pub fn into_io_error(err: impl ToString) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, err.to_string())
+    io::Error::other(err.to_string())
+}
+
+pub fn challenge_key(year: usize, day: usize) -> String {
+    format!("{}_day{}", year, day)
 }